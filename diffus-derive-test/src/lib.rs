@@ -34,6 +34,7 @@ mod test {
     #[derive(Diffus)]
     struct Lifetime<'a>(&'a u32);
 
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
     #[derive(Diffus, Debug, PartialEq)]
     struct Identified {
         id: u32,
@@ -118,6 +119,1074 @@ mod test {
         }
     }
 
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus, Debug, PartialEq)]
+    struct Unidentified {
+        id: u32,
+        value: u32,
+    }
+
+    #[derive(Diffus, Debug, PartialEq)]
+    struct Keyed {
+        #[diffus(key = "id")]
+        entries: Vec<Unidentified>,
+    }
+
+    #[derive(Diffus, Debug, PartialEq)]
+    enum KeyedEnum {
+        Entries(#[diffus(key = "id")] Vec<Unidentified>),
+    }
+
+    #[test]
+    fn keyed_collection() {
+        let left = Keyed {
+            entries: vec![
+                Unidentified { id: 1, value: 0 },
+                Unidentified { id: 2, value: 0 },
+                Unidentified { id: 3, value: 0 },
+            ],
+        };
+        let right = Keyed {
+            entries: vec![
+                Unidentified { id: 1, value: 0 },
+                Unidentified { id: 3, value: 1 },
+            ],
+        };
+
+        let diff = left.diff(&right);
+        let entries = diff.change().unwrap().entries.change().unwrap();
+
+        use edit::collection;
+
+        if let (
+            &collection::Edit::Copy(&Unidentified { id: 1, value: 0 }),
+            &collection::Edit::Remove(&Unidentified { id: 2, value: 0 }),
+            &collection::Edit::Change(EditedUnidentified {
+                id: edit::Edit::Copy(&3),
+                value: edit::Edit::Change((&0, &1)),
+            }),
+        ) = (&entries[0], &entries[1], &entries[2])
+        {
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn keyed_collection_enum() {
+        let left = KeyedEnum::Entries(vec![Unidentified { id: 1, value: 0 }]);
+        let right = KeyedEnum::Entries(vec![Unidentified { id: 1, value: 0 }]);
+
+        assert!(left.diff(&right).is_copy());
+    }
+
+    #[test]
+    fn diff_by_key() {
+        let left = vec![
+            Unidentified { id: 1, value: 0 },
+            Unidentified { id: 2, value: 0 },
+        ];
+        let right = vec![
+            Unidentified { id: 2, value: 1 },
+            Unidentified { id: 1, value: 0 },
+        ];
+
+        let diff = diffus::diff_by_key(&left, &right, |x| x.id);
+        let diff = diff.change().unwrap();
+
+        use edit::collection;
+
+        if let (
+            &collection::Edit::Remove(&Unidentified { id: 1, value: 0 }),
+            &collection::Edit::Change(EditedUnidentified {
+                id: edit::Edit::Copy(&2),
+                value: edit::Edit::Change((&0, &1)),
+            }),
+            &collection::Edit::Insert(&Unidentified { id: 1, value: 0 }),
+        ) = (&diff[0], &diff[1], &diff[2])
+        {
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[derive(Diffus, Same, Debug, PartialEq)]
+    struct DerivedIdentified {
+        #[same(id)]
+        id: u32,
+        value: u32,
+    }
+
+    #[derive(Same)]
+    struct DerivedSkip(u32, #[same(skip)] u32);
+
+    #[derive(Same)]
+    enum DerivedSameEnum {
+        A,
+        B(u32),
+        C {
+            #[same(id)]
+            id: u32,
+            value: u32,
+        },
+    }
+
+    #[test]
+    fn derive_same() {
+        assert!(DerivedIdentified { id: 1, value: 0 }.same(&DerivedIdentified { id: 1, value: 1 }));
+        assert!(!DerivedIdentified { id: 1, value: 0 }.same(&DerivedIdentified { id: 2, value: 0 }));
+
+        assert!(DerivedSkip(1, 0).same(&DerivedSkip(1, 1)));
+        assert!(!DerivedSkip(1, 0).same(&DerivedSkip(2, 0)));
+
+        assert!(DerivedSameEnum::A.same(&DerivedSameEnum::A));
+        assert!(!DerivedSameEnum::A.same(&DerivedSameEnum::B(0)));
+        assert!(DerivedSameEnum::B(0).same(&DerivedSameEnum::B(0)));
+        assert!(!DerivedSameEnum::B(0).same(&DerivedSameEnum::B(1)));
+        assert!(
+            DerivedSameEnum::C { id: 0, value: 0 }.same(&DerivedSameEnum::C { id: 0, value: 1 })
+        );
+        assert!(
+            !DerivedSameEnum::C { id: 0, value: 0 }.same(&DerivedSameEnum::C { id: 1, value: 0 })
+        );
+    }
+
+    struct NotSame;
+
+    #[derive(Same)]
+    struct DerivedGenericIdentified<T> {
+        #[same(id)]
+        id: u32,
+        payload: T,
+    }
+
+    #[derive(Same)]
+    struct DerivedGenericSkip<T, U>(T, #[same(skip)] U);
+
+    #[test]
+    fn derive_same_generic() {
+        assert!(DerivedGenericIdentified {
+            id: 1,
+            payload: NotSame
+        }
+        .same(&DerivedGenericIdentified {
+            id: 1,
+            payload: NotSame
+        }));
+        assert!(!DerivedGenericIdentified {
+            id: 1,
+            payload: NotSame
+        }
+        .same(&DerivedGenericIdentified {
+            id: 2,
+            payload: NotSame
+        }));
+
+        assert!(DerivedGenericSkip(1, NotSame).same(&DerivedGenericSkip(1, NotSame)));
+        assert!(!DerivedGenericSkip(1, NotSame).same(&DerivedGenericSkip(2, NotSame)));
+    }
+
+    #[test]
+    fn derive_same_collection() {
+        let left = vec![
+            DerivedIdentified { id: 1, value: 0 },
+            DerivedIdentified { id: 2, value: 0 },
+        ];
+        let right = vec![
+            DerivedIdentified { id: 1, value: 0 },
+            DerivedIdentified { id: 2, value: 1 },
+        ];
+
+        let diff = left.diff(&right);
+
+        use edit::collection;
+
+        if let Some(
+            &[collection::Edit::Copy(_), collection::Edit::Change(EditedDerivedIdentified {
+                id: edit::Edit::Copy(&2),
+                value: edit::Edit::Change((&0, &1)),
+            })],
+        ) = diff.change().map(Vec::as_slice)
+        {
+        } else {
+            unreachable!()
+        }
+    }
+
+    struct NotDiffable;
+
+    #[derive(Diffus)]
+    struct Skipped {
+        value: u32,
+        #[diffus(skip)]
+        timestamp: u64,
+        #[diffus(ignore)]
+        cache: NotDiffable,
+    }
+
+    #[derive(Diffus)]
+    struct SkippedUnnamed(#[diffus(skip)] u64, u32);
+
+    #[derive(Diffus)]
+    enum SkippedEnum {
+        A {
+            value: u32,
+            #[diffus(skip)]
+            timestamp: u64,
+        },
+        B(#[diffus(skip)] u64, u32),
+    }
+
+    #[test]
+    fn skip() {
+        let left = Skipped {
+            value: 0,
+            timestamp: 1,
+            cache: NotDiffable,
+        };
+        let right = Skipped {
+            value: 0,
+            timestamp: 2,
+            cache: NotDiffable,
+        };
+
+        assert!(left.diff(&right).is_copy());
+
+        let right = Skipped {
+            value: 1,
+            timestamp: 2,
+            cache: NotDiffable,
+        };
+
+        if let edit::Edit::Change(EditedSkipped { value }) = left.diff(&right) {
+            assert_eq!(value.change(), Some(&(&0, &1)));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn skip_unnamed() {
+        assert!(SkippedUnnamed(1, 0).diff(&SkippedUnnamed(2, 0)).is_copy());
+
+        if let edit::Edit::Change(EditedSkippedUnnamed(value)) =
+            SkippedUnnamed(1, 0).diff(&SkippedUnnamed(2, 1))
+        {
+            assert_eq!(value.change(), Some(&(&0, &1)));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn skip_enum() {
+        let left = SkippedEnum::A {
+            value: 0,
+            timestamp: 1,
+        };
+        let right = SkippedEnum::A {
+            value: 0,
+            timestamp: 2,
+        };
+
+        assert!(left.diff(&right).is_copy());
+        assert!(SkippedEnum::B(1, 0).diff(&SkippedEnum::B(2, 0)).is_copy());
+
+        if let Some(edit::enm::Edit::AssociatedChanged(EditedSkippedEnum::B(value))) =
+            SkippedEnum::B(1, 0).diff(&SkippedEnum::B(2, 1)).change()
+        {
+            assert_eq!(value.change(), Some(&(&0, &1)));
+        } else {
+            unreachable!()
+        }
+    }
+
+    mod case_insensitive {
+        use diffus::{edit, Diffable};
+
+        pub type Edit<'a> = edit::Edit<'a, str>;
+
+        pub fn diff<'a>(left: &'a str, right: &'a str) -> Edit<'a> {
+            if left.to_lowercase() == right.to_lowercase() {
+                edit::Edit::Copy(left)
+            } else {
+                left.diff(right)
+            }
+        }
+    }
+
+    mod unordered {
+        #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+        pub struct Edit<'a> {
+            pub inserted: Vec<&'a u32>,
+            pub removed: Vec<&'a u32>,
+        }
+
+        impl<'a> Edit<'a> {
+            pub fn is_copy(&self) -> bool {
+                self.inserted.is_empty() && self.removed.is_empty()
+            }
+        }
+
+        pub fn diff<'a>(left: &'a [u32], right: &'a [u32]) -> Edit<'a> {
+            Edit {
+                inserted: right.iter().filter(|v| !left.contains(v)).collect(),
+                removed: left.iter().filter(|v| !right.contains(v)).collect(),
+            }
+        }
+    }
+
+    #[derive(Diffus)]
+    struct With {
+        #[diffus(with = "case_insensitive")]
+        name: String,
+        #[diffus(with = "unordered")]
+        tags: Vec<u32>,
+        value: u32,
+    }
+
+    #[derive(Diffus)]
+    enum WithEnum {
+        A(#[diffus(with = "unordered")] Vec<u32>),
+    }
+
+    #[test]
+    fn with() {
+        let left = With {
+            name: "Bilbo".to_owned(),
+            tags: vec![1, 2],
+            value: 0,
+        };
+        let right = With {
+            name: "BILBO".to_owned(),
+            tags: vec![2, 1],
+            value: 0,
+        };
+
+        assert!(left.diff(&right).is_copy());
+
+        let right = With {
+            name: "Frodo".to_owned(),
+            tags: vec![2, 3],
+            value: 0,
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff.name.is_change());
+        assert_eq!(diff.tags.inserted, vec![&3]);
+        assert_eq!(diff.tags.removed, vec![&1]);
+        assert!(diff.value.is_copy());
+    }
+
+    #[test]
+    fn with_enum() {
+        assert!(WithEnum::A(vec![1, 2])
+            .diff(&WithEnum::A(vec![2, 1]))
+            .is_copy());
+
+        if let Some(edit::enm::Edit::AssociatedChanged(EditedWithEnum::A(tags))) =
+            WithEnum::A(vec![1]).diff(&WithEnum::A(vec![2])).change()
+        {
+            assert_eq!(tags.inserted, vec![&2]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[derive(Diffus, Debug, PartialEq)]
+    struct Wrapper<T> {
+        inner: T,
+    }
+
+    #[derive(Diffus, Debug, PartialEq)]
+    struct Borrowed<'a, T>(&'a T);
+
+    #[derive(Diffus)]
+    struct WithWhere<T>
+    where
+        T: Clone,
+    {
+        inner: T,
+    }
+
+    #[derive(Diffus)]
+    struct SkippedParameter<T, M> {
+        inner: T,
+        #[diffus(skip)]
+        meta: M,
+    }
+
+    #[derive(Diffus)]
+    #[diffus(bound = "T: Diffable<'diffus_a> + Same + 'diffus_a")]
+    struct Bounded<T> {
+        items: Vec<T>,
+    }
+
+    #[derive(Diffus, Debug, PartialEq)]
+    enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+
+    #[test]
+    fn generic() {
+        assert!(Wrapper { inner: 1 }.diff(&Wrapper { inner: 1 }).is_copy());
+
+        let (left, right) = (Wrapper { inner: 1 }, Wrapper { inner: 2 });
+
+        if let edit::Edit::Change(EditedWrapper { inner }) = left.diff(&right) {
+            assert_eq!(inner.change(), Some(&(&1, &2)));
+        } else {
+            unreachable!()
+        }
+
+        let left = Wrapper {
+            inner: "Bilbo".to_owned(),
+        };
+        let right = Wrapper {
+            inner: "Frodo".to_owned(),
+        };
+
+        assert!(left.diff(&right).change().unwrap().inner.is_change());
+    }
+
+    #[test]
+    fn generic_lifetime() {
+        let (left, right) = (1, 2);
+
+        if let edit::Edit::Change(EditedBorrowed(inner)) = Borrowed(&left).diff(&Borrowed(&right)) {
+            assert_eq!(inner.change(), Some(&(&1, &2)));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn generic_where_clause() {
+        assert!(WithWhere { inner: 1 }
+            .diff(&WithWhere { inner: 1 })
+            .is_copy());
+    }
+
+    #[test]
+    fn generic_skipped_parameter() {
+        struct NotDiffable;
+
+        let left = SkippedParameter {
+            inner: 1,
+            meta: NotDiffable,
+        };
+        let right = SkippedParameter {
+            inner: 2,
+            meta: NotDiffable,
+        };
+
+        if let edit::Edit::Change(EditedSkippedParameter { inner }) = left.diff(&right) {
+            assert_eq!(inner.change(), Some(&(&1, &2)));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn generic_bound() {
+        let left = Bounded { items: vec![1, 2] };
+        let right = Bounded { items: vec![1, 3] };
+
+        assert!(left.diff(&right).change().unwrap().items.is_change());
+    }
+
+    #[test]
+    fn generic_enum() {
+        let left: Either<u32, String> = Either::Left(1);
+        let right = Either::Right("Bilbo".to_owned());
+
+        assert!(left.diff(&right).change().unwrap().is_variant_changed());
+
+        if let Some(edit::enm::Edit::AssociatedChanged(EditedEither::Left(inner))) =
+            left.diff(&Either::Left(2)).change()
+        {
+            assert_eq!(inner.change(), Some(&(&1, &2)));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[derive(Diffus)]
+    struct View<'a, 'b> {
+        name: &'a str,
+        tags: &'b Vec<String>,
+        value: u32,
+    }
+
+    #[derive(Diffus)]
+    enum ViewEnum<'a, 'b, T> {
+        Name(&'a str),
+        Tagged { tag: &'b T, value: u32 },
+    }
+
+    #[derive(Diffus)]
+    struct PartialView<'a, 'b> {
+        name: &'a str,
+        #[diffus(skip)]
+        cache: &'b u32,
+    }
+
+    #[test]
+    fn multiple_lifetimes() {
+        let tags = vec!["a".to_owned()];
+        let other_tags = vec!["a".to_owned(), "b".to_owned()];
+
+        let left = View {
+            name: "Bilbo",
+            tags: &tags,
+            value: 0,
+        };
+        let right = View {
+            name: "Bilbo",
+            tags: &other_tags,
+            value: 0,
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff.name.is_copy());
+        assert!(diff.tags.is_change());
+        assert!(diff.value.is_copy());
+    }
+
+    #[test]
+    fn multiple_lifetimes_enum() {
+        let left: ViewEnum<u32> = ViewEnum::Tagged { tag: &1, value: 0 };
+        let right = ViewEnum::Tagged { tag: &2, value: 0 };
+
+        if let Some(edit::enm::Edit::AssociatedChanged(EditedViewEnum::Tagged { tag, value })) =
+            left.diff(&right).change()
+        {
+            assert_eq!(tag.change(), Some(&(&1, &2)));
+            assert!(value.is_copy());
+        } else {
+            unreachable!()
+        }
+
+        assert!(ViewEnum::<u32>::Name("Bilbo")
+            .diff(&ViewEnum::Name("Bilbo"))
+            .is_copy());
+    }
+
+    #[test]
+    fn multiple_lifetimes_skipped() {
+        let left = PartialView {
+            name: "Bilbo",
+            cache: &1,
+        };
+        let right = PartialView {
+            name: "Bilbo",
+            cache: &2,
+        };
+
+        assert!(left.diff(&right).is_copy());
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus, Debug, PartialEq)]
+    #[diffus(edited_name = "RenamedDiff", edited_derive(Debug, Clone, PartialEq))]
+    struct Renamed {
+        name: String,
+        count: u32,
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus, Debug, PartialEq)]
+    #[diffus(
+        edited_name = "RenamedEnumDiff",
+        edited_derive(Debug, Clone, PartialEq)
+    )]
+    enum RenamedEnum {
+        A(Renamed),
+        B,
+    }
+
+    #[test]
+    fn edited_name_and_derive() {
+        let left = Renamed {
+            name: "Bilbo".to_owned(),
+            count: 1,
+        };
+        let right = Renamed {
+            name: "Bilbo".to_owned(),
+            count: 2,
+        };
+
+        let diff: RenamedDiff = left.diff(&right).change().unwrap().clone();
+
+        assert_eq!(
+            diff,
+            RenamedDiff {
+                name: edit::Edit::Copy(&left.name),
+                count: edit::Edit::Change((&1, &2)),
+            }
+        );
+        assert_eq!(
+            format!("{:?}", diff),
+            "RenamedDiff { name: Copy(\"Bilbo\"), count: Change((1, 2)) }"
+        );
+    }
+
+    #[test]
+    fn edited_name_and_derive_enum() {
+        let left = RenamedEnum::A(Renamed {
+            name: "Bilbo".to_owned(),
+            count: 1,
+        });
+        let right = RenamedEnum::A(Renamed {
+            name: "Frodo".to_owned(),
+            count: 1,
+        });
+
+        let diff = left.diff(&right);
+
+        if let edit::Edit::Change(edit::enm::Edit::AssociatedChanged(RenamedEnumDiff::A(
+            edit::Edit::Change(renamed),
+        ))) = diff.clone()
+        {
+            assert!(renamed.name.is_change());
+            assert!(renamed.count.is_copy());
+        } else {
+            unreachable!()
+        }
+        assert_eq!(diff.clone(), diff);
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus, Debug, PartialEq)]
+    #[diffus(helpers)]
+    struct Account {
+        name: String,
+        email: String,
+        #[diffus(key = "id")]
+        entries: Vec<Identified>,
+        #[diffus(with = "case_insensitive")]
+        kind: String,
+        #[diffus(skip)]
+        updated_at: u64,
+    }
+
+    #[test]
+    fn edited_helpers() {
+        let left = Account {
+            name: "Bilbo".to_owned(),
+            email: "bilbo@shire.me".to_owned(),
+            entries: vec![Identified { id: 1, value: 1 }],
+            kind: "hobbit".to_owned(),
+            updated_at: 0,
+        };
+        let right = Account {
+            name: "Bilbo".to_owned(),
+            email: "bilbo@rivendell.me".to_owned(),
+            entries: vec![Identified { id: 1, value: 2 }],
+            kind: "HOBBIT".to_owned(),
+            updated_at: 1,
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(
+            diff.changed_fields().collect::<Vec<_>>(),
+            vec!["email", "entries"]
+        );
+        assert!(diff.is_changed(AccountFieldName::Email));
+        assert!(!diff.is_changed(AccountFieldName::Name));
+        assert!(!diff.is_changed(AccountFieldName::Kind));
+        assert_eq!(AccountFieldName::Email.as_str(), "email");
+
+        assert!(diff.name().is_none());
+        assert!(diff.email().is_some());
+        assert!(diff.kind().is_none());
+        if let [edit::collection::Edit::Change(EditedIdentified { id, value })] =
+            diff.entries().unwrap().as_slice()
+        {
+            assert!(id.is_copy());
+            assert_eq!(value.change(), Some(&(&1, &2)));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[derive(Diffus)]
+    struct Flags {
+        is_changed: bool,
+        changed_fields: u32,
+    }
+
+    #[test]
+    fn edited_without_helpers() {
+        let left = Flags {
+            is_changed: false,
+            changed_fields: 0,
+        };
+        let right = Flags {
+            is_changed: true,
+            changed_fields: 0,
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff.is_changed.is_change());
+        assert!(diff.changed_fields.is_copy());
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus, Debug, PartialEq)]
+    #[diffus(cross_variant, edited_derive(Debug, PartialEq))]
+    enum Shape {
+        Circle { id: u32, name: String, radius: u32 },
+        Square { id: u32, name: String, side: u32 },
+        Line { id: u32, length: u32 },
+        Point,
+    }
+
+    #[test]
+    fn cross_variant() {
+        let left = Shape::Circle {
+            id: 1,
+            name: "Sun".to_owned(),
+            radius: 2,
+        };
+        let right = Shape::Square {
+            id: 1,
+            name: "Box".to_owned(),
+            side: 2,
+        };
+
+        let diff = left.diff(&right);
+        let (_, _, variant_change) = diff.change().unwrap().variant_changed().unwrap();
+
+        assert_eq!(variant_change.from, "Circle");
+        assert_eq!(variant_change.to, "Square");
+        assert_eq!(variant_change.id, Some(edit::Edit::Copy(&1)));
+        assert!(variant_change.name.as_ref().unwrap().is_change());
+    }
+
+    #[test]
+    fn cross_variant_missing_fields() {
+        let left = Shape::Line { id: 1, length: 2 };
+        let right = Shape::Point;
+
+        let diff = left.diff(&right);
+        let (_, _, variant_change) = diff.change().unwrap().variant_changed().unwrap();
+
+        assert_eq!(
+            variant_change,
+            &EditedShapeVariantChange {
+                from: "Line",
+                to: "Point",
+                id: None,
+                name: None,
+            }
+        );
+    }
+
+    #[test]
+    fn cross_variant_same_variant() {
+        let left = Shape::Line { id: 1, length: 2 };
+        let right = Shape::Line { id: 1, length: 3 };
+
+        if let edit::Edit::Change(edit::enm::DetailedEdit::AssociatedChanged(EditedShape::Line {
+            id,
+            length,
+        })) = left.diff(&right)
+        {
+            assert!(id.is_copy());
+            assert_eq!(length.change(), Some(&(&2, &3)));
+        } else {
+            unreachable!()
+        }
+        assert!(Shape::Point.diff(&Shape::Point).is_copy());
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus)]
+    #[diffus(cross_variant)]
+    enum Tagged<T, U> {
+        A { value: T, extra: U },
+        B { value: T },
+    }
+
+    #[test]
+    fn cross_variant_generic() {
+        let left = Tagged::<u32, String>::A {
+            value: 1,
+            extra: "extra".to_owned(),
+        };
+        let right = Tagged::B { value: 2 };
+
+        let diff = left.diff(&right);
+        let (_, _, variant_change) = diff.change().unwrap().variant_changed().unwrap();
+        let _: &EditedTaggedVariantChange<u32> = variant_change;
+
+        assert_eq!(
+            variant_change.value.as_ref().unwrap().change(),
+            Some(&(&1, &2))
+        );
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus, Debug, PartialEq)]
+    #[diffus(option, helpers)]
+    struct Profile {
+        nickname: Option<String>,
+        age: Option<u32>,
+        website: Option<String>,
+        #[diffus(skip)]
+        cache: Option<u32>,
+        name: String,
+    }
+
+    #[test]
+    fn option_fields() {
+        let left = Profile {
+            nickname: None,
+            age: Some(110),
+            website: Some("shire.me".to_owned()),
+            cache: None,
+            name: "Bilbo".to_owned(),
+        };
+        let right = Profile {
+            nickname: Some("Burglar".to_owned()),
+            age: Some(111),
+            website: None,
+            cache: Some(1),
+            name: "Bilbo".to_owned(),
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(diff.nickname.set(), Some(&"Burglar".to_owned()));
+        assert_eq!(diff.age, edit::option::Edit::Changed((&110, &111)));
+        assert_eq!(diff.website.unset(), Some(&"shire.me".to_owned()));
+        assert!(diff.name.is_copy());
+        assert_eq!(
+            diff.changed_fields().collect::<Vec<_>>(),
+            vec!["nickname", "age", "website"]
+        );
+
+        assert!(left.diff(&left).is_copy());
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus)]
+    enum OptionEnum<T> {
+        A(#[diffus(option)] Option<T>, Option<T>),
+    }
+
+    #[test]
+    fn option_field_attribute() {
+        let left = OptionEnum::A(None, None);
+        let right = OptionEnum::A(Some(1), Some(1));
+
+        if let edit::Edit::Change(edit::enm::Edit::AssociatedChanged(EditedOptionEnum::A(
+            option,
+            variant,
+        ))) = left.diff(&right)
+        {
+            assert_eq!(option, edit::option::Edit::Set(&1));
+            assert!(variant.change().unwrap().is_variant_changed());
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus)]
+    #[diffus(helpers)]
+    struct SortedMaps {
+        #[diffus(sorted)]
+        scores: std::collections::HashMap<String, u32>,
+        #[diffus(sorted)]
+        tags: std::collections::HashSet<u32>,
+    }
+
+    #[test]
+    fn sorted_maps() {
+        let left = SortedMaps {
+            scores: (0..16).map(|i| (i.to_string(), i)).collect(),
+            tags: (0..16).collect(),
+        };
+        let right = SortedMaps {
+            scores: (0..16).map(|i| (i.to_string(), i * 2)).collect(),
+            tags: (0..16).collect(),
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        let mut expected = (1..16).map(|i| i.to_string()).collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(
+            diff.scores
+                .change()
+                .unwrap()
+                .iter()
+                .filter(|(_, edit)| edit.is_change())
+                .map(|(key, _)| key.as_str())
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(diff.tags.is_copy());
+        assert!(diff.scores().is_some());
+        assert!(diff.tags().is_none());
+    }
+
+    #[cfg(feature = "indexmap-impl")]
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus)]
+    #[diffus(helpers)]
+    struct OrderedMaps {
+        #[diffus(ordered)]
+        columns: indexmap::IndexMap<String, u32>,
+        #[diffus(ordered)]
+        tags: indexmap::IndexSet<u32>,
+    }
+
+    #[cfg(feature = "indexmap-impl")]
+    #[test]
+    fn ordered_maps() {
+        let left = OrderedMaps {
+            columns: vec![("id".to_owned(), 1), ("name".to_owned(), 2)]
+                .into_iter()
+                .collect(),
+            tags: (0..4).collect(),
+        };
+        let right = OrderedMaps {
+            columns: vec![("name".to_owned(), 2), ("id".to_owned(), 1)]
+                .into_iter()
+                .collect(),
+            tags: (0..4).collect(),
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(
+            diff.columns
+                .change()
+                .unwrap()
+                .iter()
+                .filter(|entry| entry.is_move())
+                .map(|entry| entry.key().as_str())
+                .collect::<Vec<_>>(),
+            vec!["id"]
+        );
+        assert!(diff.tags.is_copy());
+        assert!(diff.columns().is_some());
+        assert!(diff.tags().is_none());
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus)]
+    #[diffus(helpers)]
+    struct Migration {
+        #[diffus(renames)]
+        settings: std::collections::BTreeMap<String, String>,
+    }
+
+    #[test]
+    fn renamed_keys() {
+        let left = Migration {
+            settings: vec![("db_url".to_owned(), "postgres://".to_owned())]
+                .into_iter()
+                .collect(),
+        };
+        let right = Migration {
+            settings: vec![("database_url".to_owned(), "postgres://".to_owned())]
+                .into_iter()
+                .collect(),
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+        let settings = diff.settings().unwrap();
+
+        assert_eq!(settings.len(), 1);
+        let (from, to, value) = settings[&"database_url".to_owned()].renamed().unwrap();
+        assert_eq!((from.as_str(), to.as_str()), ("db_url", "database_url"));
+        assert!(value.is_copy());
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus)]
+    #[diffus(helpers)]
+    struct Inventory {
+        #[diffus(unordered)]
+        items: Vec<String>,
+        bag: diffus::Bag<u32>,
+    }
+
+    #[test]
+    fn unordered() {
+        let left = Inventory {
+            items: vec!["apple".to_owned(), "pear".to_owned(), "apple".to_owned()],
+            bag: vec![1, 1, 2].into_iter().collect(),
+        };
+        let right = Inventory {
+            items: vec!["pear".to_owned(), "apple".to_owned(), "apple".to_owned()],
+            bag: vec![2, 1].into_iter().collect(),
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff.items.is_copy());
+        assert!(diff.items().is_none());
+        assert_eq!(
+            diff.bag().unwrap()[&1],
+            diffus::edit::bag::Count::Decrease { from: 2, to: 1 }
+        );
+    }
+
+    #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+    #[derive(Diffus)]
+    #[diffus(helpers)]
+    struct Labels {
+        #[diffus(as_set)]
+        tags: Vec<String>,
+        #[diffus(as_map)]
+        headers: Vec<(String, String)>,
+    }
+
+    #[test]
+    fn as_set_and_map() {
+        let left = Labels {
+            tags: vec!["a".to_owned(), "b".to_owned()],
+            headers: vec![
+                ("accept".to_owned(), "*/*".to_owned()),
+                ("host".to_owned(), "a".to_owned()),
+            ],
+        };
+        let right = Labels {
+            tags: vec!["b".to_owned(), "a".to_owned()],
+            headers: vec![
+                ("host".to_owned(), "b".to_owned()),
+                ("accept".to_owned(), "*/*".to_owned()),
+            ],
+        };
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff.tags.is_copy());
+        let headers = diff.headers().unwrap();
+        assert!(headers[&"accept".to_owned()].is_copy());
+        assert!(headers[&"host".to_owned()].is_change());
+    }
+
     #[derive(Diffus)]
     enum NestedTest {
         T { test: Test },
@@ -389,6 +1458,32 @@ mod test {
                 })
             );
         }
+        #[derive(Diffus, serde::Serialize)]
+        #[diffus(edited_attr(serde(rename_all = "camelCase")))]
+        struct Camel {
+            first_name: String,
+            last_name: String,
+        }
+
+        #[test]
+        fn edited_attr() {
+            let left = Camel {
+                first_name: "Bilbo".to_owned(),
+                last_name: "Baggins".to_owned(),
+            };
+            let right = Camel {
+                first_name: "Bilbo".to_owned(),
+                last_name: "Bagginz".to_owned(),
+            };
+
+            let json = serde_json::to_value(left.diff(&right)).unwrap();
+
+            assert_eq!(
+                json["Change"]["firstName"],
+                serde_json::json!({ "Copy": "Bilbo" })
+            );
+            assert!(json["Change"]["lastName"]["Change"].is_array());
+        }
     }
 
     #[test]
@@ -0,0 +1,9 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+struct Headers {
+    #[diffus(as_map)]
+    headers: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use diffus::Diffus;
+
+mod module {}
+
+#[derive(Diffus)]
+struct Conflicting {
+    #[diffus(skip, with = "module")]
+    a: Vec<u32>,
+    #[diffus(key = "id", skip)]
+    b: Vec<u32>,
+}
+
+fn main() {}
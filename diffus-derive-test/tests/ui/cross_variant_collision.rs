@@ -0,0 +1,10 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+#[diffus(cross_variant)]
+enum Transfer {
+    Pending { from: String, to: String },
+    Done { from: String, to: String, amount: u32 },
+}
+
+fn main() {}
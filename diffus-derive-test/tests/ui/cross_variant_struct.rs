@@ -0,0 +1,10 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+#[diffus(cross_variant)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+#[diffus(helpers)]
+struct Collisions {
+    foo_bar: u32,
+    foo__bar: u32,
+    is_changed: bool,
+}
+
+#[derive(Diffus)]
+#[diffus(helpers)]
+struct Point(i32, i32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+#[diffus(bound = "T Diffable")]
+struct InvalidBound<T> {
+    a: T,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+struct InvalidWith {
+    #[diffus(with = "not a path")]
+    a: u32,
+}
+
+fn main() {}
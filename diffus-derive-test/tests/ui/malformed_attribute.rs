@@ -0,0 +1,11 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+struct Malformed {
+    #[diffus = "skip"]
+    a: u32,
+    #[diffus]
+    b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+struct Point {
+    #[diffus(option)]
+    x: i32,
+}
+
+fn main() {}
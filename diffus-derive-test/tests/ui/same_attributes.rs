@@ -0,0 +1,21 @@
+use diffus::Same;
+
+#[derive(Same)]
+struct Unsupported {
+    #[same(unknown)]
+    a: u32,
+}
+
+#[derive(Same)]
+struct Conflicting {
+    #[same(id, skip)]
+    a: u32,
+}
+
+#[derive(Same)]
+struct Malformed {
+    #[same = "id"]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use diffus::{Diffus, Same};
+
+#[derive(Diffus)]
+union DiffusUnion {
+    a: u32,
+    b: f32,
+}
+
+#[derive(Same)]
+union SameUnion {
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
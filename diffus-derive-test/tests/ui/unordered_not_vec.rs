@@ -0,0 +1,9 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+struct Basket {
+    #[diffus(unordered)]
+    items: std::collections::HashSet<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+#[diffus(skip)]
+struct Unsupported {
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+struct Unsupported {
+    #[diffus(unknown)]
+    a: u32,
+    #[diffus(skip = "yes")]
+    b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use diffus::Diffus;
+
+#[derive(Diffus)]
+enum VariantAttribute {
+    #[diffus(skip)]
+    A(u32),
+    B,
+}
+
+fn main() {}
@@ -1,32 +1,336 @@
 extern crate proc_macro;
 
+mod same;
+
 use quote::{format_ident, quote};
 
 type Output = proc_macro2::TokenStream;
 
+#[derive(Default)]
+struct FieldAttributes {
+    // The attribute the field is diffed with, as at most one can be used on a field.
+    attribute: Option<&'static str>,
+    key: Option<Output>,
+    skip: bool,
+    with: Option<syn::Path>,
+    option: Option<syn::Type>,
+    sorted: bool,
+    ordered: bool,
+    renames: bool,
+    unordered: Option<syn::Type>,
+    as_set: Option<syn::Type>,
+    as_map: Option<(syn::Type, syn::Type)>,
+}
+
+// The `T` of a field of type `Option<T>`.
+fn option_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_argument(ty, "Option")
+}
+
+// The `T` of a field of type `Vec<T>`.
+fn vec_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_argument(ty, "Vec")
+}
+
+// The `K` and `V` of a field of type `Vec<(K, V)>`.
+fn association_list_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    if let syn::Type::Tuple(tuple) = vec_type(ty)? {
+        if let (2, Some(key), Some(value)) =
+            (tuple.elems.len(), tuple.elems.first(), tuple.elems.last())
+        {
+            return Some((key, value));
+        }
+    }
+
+    None
+}
+
+fn generic_argument<'t>(ty: &'t syn::Type, name: &str) -> Option<&'t syn::Type> {
+    if let syn::Type::Path(syn::TypePath { qself: None, path }) = ty {
+        let segment = path.segments.last()?;
+
+        if let (true, syn::PathArguments::AngleBracketed(arguments)) =
+            (segment.ident == name, &segment.arguments)
+        {
+            if let (1, Some(syn::GenericArgument::Type(ty))) =
+                (arguments.args.len(), arguments.args.first())
+            {
+                return Some(ty);
+            }
+        }
+    }
+
+    None
+}
+
+// The field attributes and whether they take a string value. `ignore` is an alias of `skip`.
+const FIELD_ATTRIBUTES: &[(&str, bool)] = &[
+    ("key", true),
+    ("with", true),
+    ("option", false),
+    ("sorted", false),
+    ("ordered", false),
+    ("renames", false),
+    ("unordered", false),
+    ("as_set", false),
+    ("as_map", false),
+    ("skip", false),
+    ("ignore", false),
+];
+
+fn unsupported_field_attribute(meta: &syn::NestedMeta) -> syn::Error {
+    let expected = FIELD_ATTRIBUTES
+        .iter()
+        .map(|(name, takes_value)| {
+            if *takes_value {
+                format!("`{} = \"...\"`", name)
+            } else {
+                format!("`{}`", name)
+            }
+        })
+        .collect::<Vec<_>>();
+    let (last, expected) = expected.split_last().expect("field attributes");
+
+    syn::Error::new_spanned(
+        meta,
+        format!(
+            "unsupported diffus field attribute, expected one of {} or {}",
+            expected.join(", "),
+            last
+        ),
+    )
+}
+
+fn try_field_attributes(field: &syn::Field) -> syn::Result<FieldAttributes> {
+    let mut attributes = FieldAttributes::default();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("diffus"))
+    {
+        for meta in nested_metas(attr)? {
+            let (ident, value) = match meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => (path.get_ident(), None),
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    ref path,
+                    lit: syn::Lit::Str(ref lit),
+                    ..
+                })) => (path.get_ident(), Some(lit)),
+                _ => return Err(unsupported_field_attribute(&meta)),
+            };
+            let name = FIELD_ATTRIBUTES
+                .iter()
+                .find(|(name, takes_value)| {
+                    matches!(ident, Some(ident) if ident == name) && *takes_value == value.is_some()
+                })
+                .map(|(name, _)| *name)
+                .ok_or_else(|| unsupported_field_attribute(&meta))?;
+
+            match (name, value) {
+                ("key", Some(lit)) => attributes.key = Some(lit.parse()?),
+                ("with", Some(lit)) => attributes.with = Some(lit.parse()?),
+                ("skip", _) | ("ignore", _) => attributes.skip = true,
+                ("sorted", _) => attributes.sorted = true,
+                ("ordered", _) => attributes.ordered = true,
+                ("renames", _) => attributes.renames = true,
+                ("option", _) => {
+                    let ty = option_type(&field.ty).ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            &field.ty,
+                            "`option` requires a field of type `Option<...>`",
+                        )
+                    })?;
+
+                    attributes.option = Some(ty.clone());
+                }
+                ("unordered", _) => {
+                    let ty = vec_type(&field.ty).ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            &field.ty,
+                            "`unordered` requires a field of type `Vec<...>`",
+                        )
+                    })?;
+
+                    attributes.unordered = Some(ty.clone());
+                }
+                ("as_set", _) => {
+                    let ty = vec_type(&field.ty).ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            &field.ty,
+                            "`as_set` requires a field of type `Vec<...>`",
+                        )
+                    })?;
+
+                    attributes.as_set = Some(ty.clone());
+                }
+                ("as_map", _) => {
+                    let (key, value) = association_list_types(&field.ty).ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            &field.ty,
+                            "`as_map` requires a field of type `Vec<(..., ...)>`",
+                        )
+                    })?;
+
+                    attributes.as_map = Some((key.clone(), value.clone()));
+                }
+                _ => unreachable!("every field attribute is handled"),
+            }
+
+            let name = if name == "ignore" { "skip" } else { name };
+            match attributes.attribute {
+                Some(other) if other != name => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        format!(
+                            "`{}` and `{}` can not be combined on the same field",
+                            other, name
+                        ),
+                    ))
+                }
+                _ => attributes.attribute = Some(name),
+            }
+        }
+    }
+
+    Ok(attributes)
+}
+
+// Attributes are checked by `check_attributes` before any code is generated.
+fn field_attributes(field: &syn::Field) -> FieldAttributes {
+    try_field_attributes(field).expect("field attributes are checked before expansion")
+}
+
+fn nested_metas(
+    attr: &syn::Attribute,
+) -> syn::Result<syn::punctuated::Punctuated<syn::NestedMeta, syn::Token![,]>> {
+    match attr.parse_meta()? {
+        syn::Meta::List(syn::MetaList { nested, .. }) => Ok(nested),
+        meta => Err(syn::Error::new_spanned(
+            meta,
+            format!(
+                "expected #[{}(...)]",
+                attr.path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default()
+            ),
+        )),
+    }
+}
+
+fn combine_errors(errors: impl IntoIterator<Item = syn::Error>) -> syn::Result<()> {
+    let mut errors = errors.into_iter();
+
+    match errors.next() {
+        Some(mut error) => {
+            errors.for_each(|other| error.combine(other));
+            Err(error)
+        }
+        None => Ok(()),
+    }
+}
+
+fn is_diffed(field: &syn::Field) -> bool {
+    !field_attributes(field).skip
+}
+
+fn edit_field_type(field: &syn::Field, lifetime: &syn::Lifetime) -> Output {
+    let ty = &field.ty;
+    let attributes = field_attributes(field);
+
+    if let Some(with) = attributes.with {
+        quote! { #with::Edit<#lifetime> }
+    } else if let Some(option) = attributes.option {
+        quote! { diffus::edit::option::Edit<#lifetime, #option> }
+    } else if attributes.sorted {
+        quote! { diffus::edit::sorted::Edit<#lifetime, #ty> }
+    } else if attributes.ordered {
+        quote! { diffus::edit::ordered::Edit<#lifetime, #ty> }
+    } else if attributes.renames {
+        quote! { diffus::edit::renamed::Edit<#lifetime, #ty> }
+    } else if let Some(unordered) = attributes.unordered {
+        quote! { diffus::edit::bag::Edit<#lifetime, #unordered> }
+    } else if let Some(as_set) = attributes.as_set {
+        quote! { diffus::edit::as_set::Edit<#lifetime, #as_set> }
+    } else if let Some((key, value)) = attributes.as_map {
+        quote! { diffus::edit::as_map::Edit<#lifetime, #key, #value> }
+    } else if attributes.key.is_some() {
+        quote! { diffus::edit::keyed::Edit<#lifetime, #ty> }
+    } else {
+        quote! { diffus::edit::Edit<#lifetime, #ty> }
+    }
+}
+
 fn edit_fields(fields: &syn::Fields, lifetime: &syn::Lifetime) -> Output {
-    let edit_fields = fields.iter().map(|field| match field {
-        syn::Field {
-            ident: Some(ident),
-            ty,
-            vis,
-            ..
-        } => quote! {
-            #vis #ident: diffus::edit::Edit<#lifetime, #ty>
-        },
-        syn::Field {
-            ident: None,
-            ty,
-            vis,
-            ..
-        } => quote! {
-            #vis diffus::edit::Edit<#lifetime, #ty>
-        },
+    let edit_fields = fields.iter().filter(|field| is_diffed(field)).map(|field| {
+        let edit_field_type = edit_field_type(field, lifetime);
+
+        match field {
+            syn::Field {
+                ident: Some(ident),
+                vis,
+                ..
+            } => quote! {
+                #vis #ident: #edit_field_type
+            },
+            syn::Field {
+                ident: None, vis, ..
+            } => quote! {
+                #vis #edit_field_type
+            },
+        }
     });
 
     quote! { #(#edit_fields),* }
 }
 
+fn field_diff(field: &syn::Field, left: Output, right: Output) -> Output {
+    let attributes = field_attributes(field);
+
+    if let Some(with) = attributes.with {
+        quote! {
+            #with::diff(#left, #right)
+        }
+    } else if let Some(key) = attributes.key {
+        quote! {
+            diffus::diff_by_key(#left, #right, |item| &item.#key)
+        }
+    } else if attributes.option.is_some() {
+        quote! {
+            diffus::diff_option(#left, #right)
+        }
+    } else if attributes.sorted {
+        quote! {
+            diffus::diff_sorted(#left, #right)
+        }
+    } else if attributes.ordered {
+        quote! {
+            diffus::diff_ordered(#left, #right)
+        }
+    } else if attributes.renames {
+        quote! {
+            diffus::diff_renames(#left, #right)
+        }
+    } else if attributes.unordered.is_some() {
+        quote! {
+            diffus::diff_unordered(#left, #right)
+        }
+    } else if attributes.as_set.is_some() {
+        quote! {
+            diffus::diff_as_set(#left, #right)
+        }
+    } else if attributes.as_map.is_some() {
+        quote! {
+            diffus::diff_as_map(#left, #right)
+        }
+    } else {
+        quote! {
+            diffus::Diffable::diff(#left, #right)
+        }
+    }
+}
+
 fn field_ident(enumerated_field: (usize, &syn::Field), prefix: &str) -> syn::Ident {
     match enumerated_field {
         (
@@ -45,11 +349,26 @@ fn field_idents(fields: &syn::Fields, prefix: &str) -> Output {
     let field_idents = fields
         .iter()
         .enumerate()
+        .filter(|(_, field)| is_diffed(field))
         .map(|enumerated_field| field_ident(enumerated_field, prefix));
 
     quote! { #(#field_idents),* }
 }
 
+fn field_patterns(fields: &syn::Fields, prefix: &str) -> Output {
+    let field_patterns = fields.iter().enumerate().map(|enumerated_field| {
+        if is_diffed(enumerated_field.1) {
+            let field_ident = field_ident(enumerated_field, prefix);
+
+            quote! { #field_ident }
+        } else {
+            quote! { _ }
+        }
+    });
+
+    quote! { #(#field_patterns),* }
+}
+
 fn renamed_field_ident(enumerated_field: (usize, &syn::Field), prefix: &str) -> Output {
     match enumerated_field {
         (
@@ -58,9 +377,13 @@ fn renamed_field_ident(enumerated_field: (usize, &syn::Field), prefix: &str) ->
                 ident: Some(ident), ..
             },
         ) => {
-            let new_ident = format_ident!("{}{}", prefix, ident);
+            if is_diffed(enumerated_field.1) {
+                let new_ident = format_ident!("{}{}", prefix, ident);
 
-            quote! { #ident: #new_ident }
+                quote! { #ident: #new_ident }
+            } else {
+                quote! { #ident: _ }
+            }
         }
         (_, syn::Field { ident: None, .. }) => unreachable!(),
     }
@@ -75,33 +398,79 @@ fn renamed_field_idents(fields: &syn::Fields, prefix: &str) -> Output {
     quote! { #(#field_idents),* }
 }
 
+// Fields diffed through `#[diffus(with = "...")]` can not be matched on, so they are bound and
+// checked through their `is_copy` method instead.
 fn matches_all_copy(fields: &syn::Fields) -> Output {
-    let edit_fields_copy = fields.iter().enumerate().map(|_| {
-        quote! { diffus::edit::Edit::Copy(_) }
-    });
+    let mut guards = Vec::new();
+
+    let edit_fields_copy = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| is_diffed(field))
+        .map(|(index, field)| {
+            let attributes = field_attributes(field);
+
+            if attributes.with.is_some() {
+                let ident = field_ident((index, field), "copy_");
+                guards.push(quote! { #ident.is_copy() });
+
+                quote! { #ident }
+            } else if attributes.key.is_some() {
+                quote! { diffus::edit::keyed::Edit::Copy(_) }
+            } else if attributes.option.is_some() {
+                quote! { diffus::edit::option::Edit::Copy(_) }
+            } else if attributes.sorted {
+                quote! { diffus::edit::sorted::Edit::Copy(_) }
+            } else if attributes.ordered {
+                quote! { diffus::edit::ordered::Edit::Copy(_) }
+            } else if attributes.renames {
+                quote! { diffus::edit::renamed::Edit::Copy(_) }
+            } else if attributes.unordered.is_some() {
+                quote! { diffus::edit::bag::Edit::Copy(_) }
+            } else if attributes.as_set.is_some() {
+                quote! { diffus::edit::as_set::Edit::Copy(_) }
+            } else if attributes.as_map.is_some() {
+                quote! { diffus::edit::as_map::Edit::Copy(_) }
+            } else {
+                quote! { diffus::edit::Edit::Copy(_) }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let guard = if guards.is_empty() {
+        None
+    } else {
+        Some(quote! { if #(#guards)&&* })
+    };
 
     quote! {
-        ( #(#edit_fields_copy),* ) => diffus::edit::Edit::Copy(self)
+        ( #(#edit_fields_copy),* ) #guard => diffus::edit::Edit::Copy(self)
     }
 }
 
 fn field_diffs(fields: &syn::Fields) -> Output {
-    let field_diffs = fields.iter().enumerate().map(|(index, field)| {
-        let field_name = match field {
-            syn::Field {
-                ident: Some(ident), ..
-            } => quote! { #ident },
-            syn::Field { ident: None, .. } => {
-                let ident = unnamed_field_name(index);
+    let field_diffs = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| is_diffed(field))
+        .map(|(index, field)| {
+            let field_name = match field {
+                syn::Field {
+                    ident: Some(ident), ..
+                } => quote! { #ident },
+                syn::Field { ident: None, .. } => {
+                    let ident = unnamed_field_name(index);
 
-                quote! { #ident }
-            }
-        };
+                    quote! { #ident }
+                }
+            };
 
-        quote! {
-            diffus::Diffable::diff(&self.#field_name, &other.#field_name)
-        }
-    });
+            field_diff(
+                field,
+                quote! { &self.#field_name },
+                quote! { &other.#field_name },
+            )
+        });
 
     quote! { #(#field_diffs),* }
 }
@@ -113,47 +482,823 @@ fn unnamed_field_name(i: usize) -> syn::Lit {
     syn::parse_str(&format!("{}", i as u32)).unwrap()
 }
 
-fn input_lifetime(generics: &syn::Generics) -> Option<&syn::Lifetime> {
-    let mut lifetimes = generics.params.iter().filter_map(|generic_param| {
-        if let syn::GenericParam::Lifetime(syn::LifetimeDef { lifetime, .. }) = generic_param {
-            Some(lifetime)
-        } else {
+#[derive(Default)]
+struct ContainerAttributes {
+    bound: Option<Vec<syn::WherePredicate>>,
+    edited_name: Option<syn::Ident>,
+    edited_derive: Vec<syn::Path>,
+    edited_attr: Vec<syn::Meta>,
+    cross_variant: Option<syn::Path>,
+    option: bool,
+    helpers: Option<syn::Path>,
+}
+
+fn container_attributes(attrs: &[syn::Attribute]) -> syn::Result<ContainerAttributes> {
+    let mut attributes = ContainerAttributes::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("diffus")) {
+        for meta in nested_metas(attr)? {
+            match meta {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    ref path,
+                    lit: syn::Lit::Str(ref lit),
+                    ..
+                })) if path.is_ident("bound") => {
+                    let predicates = lit.parse_with(
+                        syn::punctuated::Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated,
+                    )?;
+
+                    attributes.bound = Some(predicates.into_iter().collect());
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    ref path,
+                    lit: syn::Lit::Str(ref lit),
+                    ..
+                })) if path.is_ident("edited_name") => {
+                    attributes.edited_name = Some(lit.parse()?);
+                }
+                syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList {
+                    ref path,
+                    ref nested,
+                    ..
+                })) if path.is_ident("edited_derive") => {
+                    for nested_meta in nested {
+                        match nested_meta {
+                            syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                                attributes.edited_derive.push(path.clone())
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    nested_meta,
+                                    "expected a path to a derive macro",
+                                ))
+                            }
+                        }
+                    }
+                }
+                syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList {
+                    ref path,
+                    ref nested,
+                    ..
+                })) if path.is_ident("edited_attr") => {
+                    for nested_meta in nested {
+                        match nested_meta {
+                            syn::NestedMeta::Meta(meta) => {
+                                attributes.edited_attr.push(meta.clone())
+                            }
+                            syn::NestedMeta::Lit(_) => {
+                                return Err(syn::Error::new_spanned(
+                                    nested_meta,
+                                    "expected an attribute",
+                                ))
+                            }
+                        }
+                    }
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(ref path))
+                    if path.is_ident("cross_variant") =>
+                {
+                    attributes.cross_variant = Some(path.clone());
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("option") => {
+                    attributes.option = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("helpers") => {
+                    attributes.helpers = Some(path.clone());
+                }
+                _ => return Err(syn::Error::new_spanned(
+                    meta,
+                    "unsupported diffus container attribute, expected one of `bound = \"...\"`, \
+                         `edited_name = \"...\"`, `edited_derive(...)`, `edited_attr(...)`, \
+                         `cross_variant`, `option` or `helpers`",
+                )),
+            }
+        }
+    }
+
+    Ok(attributes)
+}
+
+fn check_attributes(data: &syn::Data) -> syn::Result<()> {
+    let field_errors = all_fields(data)
+        .into_iter()
+        .filter_map(|field| try_field_attributes(field).err());
+
+    let variant_errors = match data {
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants
+            .iter()
+            .flat_map(|syn::Variant { attrs, .. }| attrs)
+            .filter(|attr| attr.path.is_ident("diffus"))
+            .map(|attr| {
+                syn::Error::new_spanned(attr, "diffus attributes are not supported on variants")
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    combine_errors(field_errors.chain(variant_errors))
+}
+
+fn mentions(tokens: Output, ident: &syn::Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(token) => token == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+fn generic_param_ident(generic_param: &syn::GenericParam) -> &syn::Ident {
+    match generic_param {
+        syn::GenericParam::Type(syn::TypeParam { ident, .. }) => ident,
+        syn::GenericParam::Lifetime(syn::LifetimeDef { lifetime, .. }) => &lifetime.ident,
+        syn::GenericParam::Const(syn::ConstParam { ident, .. }) => ident,
+    }
+}
+
+fn all_fields(data: &syn::Data) -> Vec<&syn::Field> {
+    match data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => fields.iter().collect(),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants
+            .iter()
+            .flat_map(|syn::Variant { fields, .. }| fields.iter())
+            .collect(),
+        syn::Data::Union(_) => Vec::new(),
+    }
+}
+
+// `#[diffus(option)]` on the type applies to every `Option` field without attributes of its own.
+fn diff_option_fields(data: &mut syn::Data) {
+    let fields = match data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => fields.iter_mut().collect(),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants
+            .iter_mut()
+            .flat_map(|syn::Variant { fields, .. }| fields.iter_mut())
+            .collect(),
+        syn::Data::Union(_) => Vec::new(),
+    };
+
+    for field in fields {
+        if option_type(&field.ty).is_some() && field_attributes(field).attribute.is_none() {
+            field.attrs.push(syn::parse_quote! { #[diffus(option)] });
+        }
+    }
+}
+
+// The generic parameters besides the diff lifetime that the `Edited*` type is generic over, i.e.
+// the ones used by the types of the diffed fields. Fields diffed through `with` do not carry their
+// type over.
+fn edited_generic_params<'a>(
+    generics: &'a syn::Generics,
+    fields: &[&syn::Field],
+    impl_lifetime: &syn::Lifetime,
+) -> Vec<&'a syn::GenericParam> {
+    let field_types = fields
+        .iter()
+        .filter(|field| {
+            let attributes = field_attributes(field);
+            !attributes.skip && attributes.with.is_none()
+        })
+        .map(|field| &field.ty)
+        .collect::<Vec<_>>();
+
+    generics
+        .params
+        .iter()
+        .filter(|generic_param| generic_param_ident(generic_param) != &impl_lifetime.ident)
+        .filter(|generic_param| {
+            let ident = generic_param_ident(generic_param);
+
+            field_types
+                .iter()
+                .any(|field_type| mentions(quote! { #field_type }, ident))
+        })
+        .collect()
+}
+
+// The generics of an `Edited*` type, which keep the diff lifetime if it is used, the generic
+// parameters in `edited_generic_params` and the predicates only mentioning those.
+fn edited_generics(
+    generics: &syn::Generics,
+    bounds: &[syn::WherePredicate],
+    edited_generic_params: &[&syn::GenericParam],
+    has_lifetime: bool,
+    impl_lifetime: &syn::Lifetime,
+) -> syn::Generics {
+    let excluded_idents = generics
+        .params
+        .iter()
+        .filter(|generic_param| {
+            generic_param_ident(generic_param) != &impl_lifetime.ident
+                && !edited_generic_params.iter().any(|edited_generic_param| {
+                    generic_param_ident(edited_generic_param) == generic_param_ident(generic_param)
+                })
+        })
+        .map(generic_param_ident)
+        .chain(if has_lifetime {
             None
+        } else {
+            Some(&impl_lifetime.ident)
+        })
+        .collect::<Vec<_>>();
+
+    let mut edited_generics = syn::Generics::default();
+    if has_lifetime {
+        edited_generics
+            .params
+            .push(syn::GenericParam::Lifetime(syn::LifetimeDef::new(
+                impl_lifetime.clone(),
+            )));
+    }
+    edited_generics
+        .params
+        .extend(edited_generic_params.iter().cloned().cloned());
+    edited_generics.make_where_clause().predicates.extend(
+        bounds
+            .iter()
+            .chain(
+                generics
+                    .where_clause
+                    .iter()
+                    .flat_map(|where_clause| where_clause.predicates.iter()),
+            )
+            .filter(|predicate| {
+                excluded_idents
+                    .iter()
+                    .all(|ident| !mentions(quote! { #predicate }, ident))
+            })
+            .cloned(),
+    );
+
+    edited_generics
+}
+
+// The edit types of `fields` that mention any of `edited_generic_params`.
+fn generic_edit_field_types(
+    fields: &[&syn::Field],
+    edited_generic_params: &[&syn::GenericParam],
+    impl_lifetime: &syn::Lifetime,
+) -> Vec<Output> {
+    if edited_generic_params.is_empty() {
+        Vec::new()
+    } else {
+        fields
+            .iter()
+            .map(|field| edit_field_type(field, impl_lifetime))
+            .filter(|edited_field_type| {
+                edited_generic_params.iter().any(|generic_param| {
+                    mentions(
+                        edited_field_type.clone(),
+                        generic_param_ident(generic_param),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+// Named fields diffed the same way in at least two variants, used to describe what changed when
+// an enum switches variant.
+fn common_fields<'a>(
+    variants: impl Iterator<Item = &'a syn::Variant>,
+    lifetime: &syn::Lifetime,
+) -> Vec<&'a syn::Field> {
+    let mut fields: Vec<(&syn::Field, String, usize)> = Vec::new();
+
+    for field in variants.flat_map(|syn::Variant { fields, .. }| fields.iter()) {
+        if field.ident.is_none() || !is_diffed(field) {
+            continue;
+        }
+        let diff = format!(
+            "{} {}",
+            edit_field_type(field, lifetime),
+            field_diff(field, quote! { left }, quote! { right })
+        );
+
+        match fields
+            .iter_mut()
+            .find(|(common_field, _, _)| common_field.ident == field.ident)
+        {
+            Some((_, common_diff, count)) if *common_diff == diff => *count += 1,
+            Some((_, common_diff, count)) => {
+                common_diff.clear();
+                *count = 0;
+            }
+            None => fields.push((field, diff, 1)),
+        }
+    }
+
+    fields
+        .into_iter()
+        .filter(|(_, _, count)| *count > 1)
+        .map(|(field, _, _)| field)
+        .collect()
+}
+
+#[cfg(feature = "serialize-impl")]
+fn derive_serialize(edited_field_types: &[Output]) -> Option<Output> {
+    if edited_field_types.is_empty() {
+        Some(quote! { #[derive(serde::Serialize)] })
+    } else {
+        let bound = quote! { #(#edited_field_types: serde::Serialize),* }.to_string();
+
+        Some(quote! {
+            #[derive(serde::Serialize)]
+            #[serde(bound = #bound)]
+        })
+    }
+}
+
+#[cfg(not(feature = "serialize-impl"))]
+fn derive_serialize(_edited_field_types: &[Output]) -> Option<Output> {
+    None
+}
+
+fn upper_camel_case(snake_case: &str) -> String {
+    snake_case
+        .trim_start_matches("r#")
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+// The type of the diff returned by the getter of a changed field, along with how to get it.
+fn changed_field_getter(field: &syn::Field, lifetime: &syn::Lifetime) -> (Output, Output) {
+    let ident = &field.ident;
+    let ty = &field.ty;
+    let attributes = field_attributes(field);
+
+    if attributes.with.is_some() || attributes.option.is_some() {
+        (
+            edit_field_type(field, lifetime),
+            quote! {
+                if self.#ident.is_copy() {
+                    None
+                } else {
+                    Some(&self.#ident)
+                }
+            },
+        )
+    } else if attributes.key.is_some() {
+        (
+            quote! { diffus::edit::keyed::Diff<#lifetime, #ty> },
+            quote! { self.#ident.change() },
+        )
+    } else if attributes.sorted {
+        (
+            quote! { <#ty as diffus::SortedDiffable<#lifetime>>::SortedDiff },
+            quote! { self.#ident.change() },
+        )
+    } else if attributes.ordered {
+        (
+            quote! { <#ty as diffus::OrderedDiffable<#lifetime>>::OrderedDiff },
+            quote! { self.#ident.change() },
+        )
+    } else if attributes.renames {
+        (
+            quote! { <#ty as diffus::RenameDiffable<#lifetime>>::RenameDiff },
+            quote! { self.#ident.change() },
+        )
+    } else if let Some(unordered) = attributes.unordered {
+        (
+            quote! { diffus::edit::bag::Diff<#lifetime, #unordered> },
+            quote! { self.#ident.change() },
+        )
+    } else if let Some(as_set) = attributes.as_set {
+        (
+            quote! { diffus::edit::as_set::Diff<#lifetime, #as_set> },
+            quote! { self.#ident.change() },
+        )
+    } else if let Some((key, value)) = attributes.as_map {
+        (
+            quote! { diffus::edit::as_map::Diff<#lifetime, #key, #value> },
+            quote! { self.#ident.change() },
+        )
+    } else {
+        (
+            quote! { <#ty as diffus::Diffable<#lifetime>>::Diff },
+            quote! { self.#ident.change() },
+        )
+    }
+}
+
+// Inspection helpers for the `Edited*` type of a struct with named fields: a `{Ident}FieldName`
+// enum, `changed_fields`, `is_changed` and a getter per field returning the diff if it changed.
+// Fields whose getter or variant would collide are reported instead of generating invalid code.
+fn edited_helpers(
+    ident: &syn::Ident,
+    vis: &syn::Visibility,
+    fields: &syn::Fields,
+    lifetime: &syn::Lifetime,
+    edited_type: Output,
+    edited_impl: Output,
+    edited_where_clause: Output,
+) -> syn::Result<Output> {
+    let field_name_ident = format_ident!("{}FieldName", ident);
+    let diffed_fields = fields
+        .iter()
+        .filter(|field| is_diffed(field))
+        .collect::<Vec<_>>();
+    let field_idents = diffed_fields
+        .iter()
+        .map(|field| field.ident.as_ref().expect("named field"))
+        .collect::<Vec<_>>();
+    let names = field_idents
+        .iter()
+        .map(|ident| ident.to_string().trim_start_matches("r#").to_owned())
+        .collect::<Vec<_>>();
+    let variant_names = names
+        .iter()
+        .map(|name| upper_camel_case(name))
+        .collect::<Vec<_>>();
+
+    let mut errors = Vec::new();
+    for (index, (field_ident, name)) in field_idents.iter().zip(&names).enumerate() {
+        if name == "changed_fields" || name == "is_changed" {
+            errors.push(syn::Error::new_spanned(
+                field_ident,
+                format!(
+                    "the getter of field `{}` collides with the `{}` helper",
+                    name, name
+                ),
+            ));
+        } else if variant_names[index].is_empty() {
+            errors.push(syn::Error::new_spanned(
+                field_ident,
+                format!(
+                    "field `{}` has no `{}` variant name",
+                    name, field_name_ident
+                ),
+            ));
+        } else if let Some(other) = variant_names[..index]
+            .iter()
+            .position(|variant_name| *variant_name == variant_names[index])
+        {
+            errors.push(syn::Error::new_spanned(
+                field_ident,
+                format!(
+                    "fields `{}` and `{}` both map to the `{}::{}` variant",
+                    names[other], name, field_name_ident, variant_names[index]
+                ),
+            ));
+        }
+    }
+    combine_errors(errors)?;
+
+    let variants = variant_names
+        .iter()
+        .map(|variant_name| format_ident!("{}", variant_name))
+        .collect::<Vec<_>>();
+    let getters = diffed_fields.iter().map(|field| {
+        let field_ident = &field.ident;
+        let field_vis = &field.vis;
+        let (diff_type, diff) = changed_field_getter(field, lifetime);
+
+        quote! {
+            #field_vis fn #field_ident(&self) -> Option<&#diff_type> {
+                #diff
+            }
         }
     });
 
-    let lifetime = lifetimes.next();
+    Ok(quote! {
+        #[allow(dead_code)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #field_name_ident {
+            #(#variants),*
+        }
 
-    assert!(
-        lifetimes.next().is_none(),
-        "Multiple lifetimes not supported yet"
-    );
+        #[allow(dead_code)]
+        impl #field_name_ident {
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #names),*
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl #edited_impl #edited_type #edited_where_clause {
+            pub fn changed_fields(&self) -> impl Iterator<Item = &'static str> {
+                let changed: Vec<(#field_name_ident, bool)> = vec![
+                    #((#field_name_ident::#variants, !self.#field_idents.is_copy())),*
+                ];
+
+                changed
+                    .into_iter()
+                    .filter(|(_, changed)| *changed)
+                    .map(|(field_name, _)| field_name.as_str())
+            }
+
+            pub fn is_changed(&self, field_name: #field_name_ident) -> bool {
+                match field_name {
+                    #(#field_name_ident::#variants => !self.#field_idents.is_copy()),*
+                }
+            }
+
+            #(#getters)*
+        }
+    })
+}
 
-    lifetime
+fn input_lifetimes(generics: &syn::Generics) -> Vec<&syn::Lifetime> {
+    generics
+        .params
+        .iter()
+        .filter_map(|generic_param| {
+            if let syn::GenericParam::Lifetime(syn::LifetimeDef { lifetime, .. }) = generic_param {
+                Some(lifetime)
+            } else {
+                None
+            }
+        })
+        .collect()
 }
 
-#[proc_macro_derive(Diffus)]
+#[proc_macro_derive(Diffus, attributes(diffus))]
 pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input: syn::DeriveInput = syn::parse2(proc_macro2::TokenStream::from(input)).unwrap();
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    proc_macro::TokenStream::from(
+        expand_diffus(input).unwrap_or_else(|error| error.to_compile_error()),
+    )
+}
+
+fn expand_diffus(mut input: syn::DeriveInput) -> syn::Result<Output> {
+    if let syn::Data::Union(syn::DataUnion { union_token, .. }) = input.data {
+        return Err(syn::Error::new_spanned(
+            union_token,
+            "derive(Diffus) is not supported for unions",
+        ));
+    }
+
+    let container_attributes = container_attributes(&input.attrs);
+    check_attributes(&input.data)?;
+    let container_attributes = container_attributes?;
+    if container_attributes.option {
+        diff_option_fields(&mut input.data);
+    }
 
     let ident = &input.ident;
     let vis = &input.vis;
-    let where_clause = &input.generics.where_clause;
-    let edited_ident = syn::parse_str::<syn::Path>(&format!("Edited{}", ident)).unwrap();
+    if let (Some(cross_variant), syn::Data::Struct(_)) =
+        (&container_attributes.cross_variant, &input.data)
+    {
+        return Err(syn::Error::new_spanned(
+            cross_variant,
+            "`cross_variant` is only supported on enums",
+        ));
+    }
+    match (&container_attributes.helpers, &input.data) {
+        (
+            Some(_),
+            syn::Data::Struct(syn::DataStruct {
+                fields: syn::Fields::Named(_),
+                ..
+            }),
+        )
+        | (None, _) => {}
+        (Some(helpers), _) => {
+            return Err(syn::Error::new_spanned(
+                helpers,
+                "`helpers` is only supported on structs with named fields",
+            ))
+        }
+    }
 
-    let data_lifetime = input_lifetime(&input.generics);
+    let edited_ident = container_attributes
+        .edited_name
+        .clone()
+        .unwrap_or_else(|| syn::Ident::new(&format!("Edited{}", ident), ident.span()));
+
+    // A type with a single lifetime is diffed over that lifetime, otherwise a new diff lifetime is
+    // introduced that all of the lifetimes of the type outlive.
+    let data_lifetimes = input_lifetimes(&input.generics);
     let default_lifetime = syn::parse_str::<syn::Lifetime>("'diffus_a").unwrap();
-    let impl_lifetime = data_lifetime.unwrap_or(&default_lifetime);
+    let impl_lifetime = match data_lifetimes.as_slice() {
+        [data_lifetime] => data_lifetime,
+        _ => &default_lifetime,
+    };
+    let introduces_lifetime = data_lifetimes.len() != 1;
+
+    let fields = all_fields(&input.data);
+    let diffed_fields = fields
+        .iter()
+        .cloned()
+        .filter(|field| is_diffed(field))
+        .collect::<Vec<_>>();
+    let edited_generic_params = edited_generic_params(&input.generics, &fields, impl_lifetime);
+    let edited_has_lifetime = !diffed_fields.is_empty();
 
-    #[cfg(feature = "serialize-impl")]
-    let derive_serialize = Some(quote! { #[derive(serde::Serialize)] });
-    #[cfg(not(feature = "serialize-impl"))]
-    let derive_serialize: Option<proc_macro2::TokenStream> = None;
+    let bounds = container_attributes.bound.unwrap_or_else(|| {
+        edited_generic_params
+            .iter()
+            .filter_map(|generic_param| match generic_param {
+                syn::GenericParam::Type(syn::TypeParam { ident, .. }) => Some(syn::parse_quote! {
+                    #ident: diffus::Diffable<#impl_lifetime> + #impl_lifetime
+                }),
+                _ => None,
+            })
+            .collect()
+    });
+    let bounds = data_lifetimes
+        .iter()
+        .filter(|data_lifetime| **data_lifetime != impl_lifetime)
+        .map(|data_lifetime| syn::parse_quote! { #data_lifetime: #impl_lifetime })
+        .chain(bounds)
+        .collect::<Vec<syn::WherePredicate>>();
 
-    proc_macro::TokenStream::from(match input.data {
+    let mut impl_generics = input.generics.clone();
+    if introduces_lifetime {
+        impl_generics.params.insert(
+            0,
+            syn::GenericParam::Lifetime(syn::LifetimeDef::new(impl_lifetime.clone())),
+        );
+    }
+    impl_generics
+        .make_where_clause()
+        .predicates
+        .extend(bounds.iter().cloned());
+    let (impl_generics, _, impl_where_clause) = impl_generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let edited_generics = edited_generics(
+        &input.generics,
+        &bounds,
+        &edited_generic_params,
+        edited_has_lifetime,
+        impl_lifetime,
+    );
+    let (edited_impl_generics, edited_ty_generics, edited_where_clause) =
+        edited_generics.split_for_impl();
+
+    let edited_field_types =
+        generic_edit_field_types(&diffed_fields, &edited_generic_params, impl_lifetime);
+    let derive_serialize = derive_serialize(&edited_field_types);
+    let edited_derive = &container_attributes.edited_derive;
+    let edited_attr = &container_attributes.edited_attr;
+    let edited_attributes = quote! {
+        #(#[derive(#edited_derive)])*
+        #(#[#edited_attr])*
+    };
+
+    Ok(match input.data {
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let enm_edit = if container_attributes.cross_variant.is_some() {
+                quote! { diffus::edit::enm::DetailedEdit }
+            } else {
+                quote! { diffus::edit::enm::Edit }
+            };
+
+            let (variant_change, diff_type, variant_changed) = if container_attributes
+                .cross_variant
+                .is_some()
+            {
+                let common_fields = common_fields(variants.iter(), impl_lifetime);
+                let variant_change_ident = format_ident!("{}VariantChange", edited_ident);
+                combine_errors(
+                    common_fields
+                        .iter()
+                        .filter_map(|field| field.ident.as_ref())
+                        .filter(|field_ident| *field_ident == "from" || *field_ident == "to")
+                        .map(|field_ident| {
+                            syn::Error::new_spanned(
+                                field_ident,
+                                format!(
+                                    "the shared field `{}` collides with the `{}` variant name of `{}`",
+                                    field_ident, field_ident, variant_change_ident
+                                ),
+                            )
+                        }),
+                )?;
+                let variant_change_generic_params =
+                    crate::edited_generic_params(&input.generics, &common_fields, impl_lifetime);
+                let variant_change_generics = crate::edited_generics(
+                    &input.generics,
+                    &bounds,
+                    &variant_change_generic_params,
+                    !common_fields.is_empty(),
+                    impl_lifetime,
+                );
+                let (
+                    variant_change_impl_generics,
+                    variant_change_ty_generics,
+                    variant_change_where_clause,
+                ) = variant_change_generics.split_for_impl();
+                let derive_variant_change_serialize =
+                    crate::derive_serialize(&generic_edit_field_types(
+                        &common_fields,
+                        &variant_change_generic_params,
+                        impl_lifetime,
+                    ));
+
+                let variant_names = variants
+                    .iter()
+                    .map(
+                        |syn::Variant {
+                             ident: variant_ident,
+                             ..
+                         }| {
+                            let name = variant_ident.to_string();
+
+                            quote! { #ident::#variant_ident { .. } => #name }
+                        },
+                    )
+                    .collect::<Vec<_>>();
+
+                let common_field_idents = common_fields
+                    .iter()
+                    .map(|field| &field.ident)
+                    .collect::<Vec<_>>();
+                let common_field_types = common_fields
+                    .iter()
+                    .map(|field| edit_field_type(field, impl_lifetime));
+                let common_field_diffs = common_fields.iter().map(|field| {
+                    let field_ident = &field.ident;
+                    let field_diff = field_diff(field, quote! { left }, quote! { right });
+                    let variants_with_field = variants
+                        .iter()
+                        .filter(|syn::Variant { fields, .. }| {
+                            fields
+                                .iter()
+                                .any(|variant_field| variant_field.ident == field.ident)
+                        })
+                        .map(
+                            |syn::Variant {
+                                 ident: variant_ident,
+                                 ..
+                             }| variant_ident,
+                        )
+                        .collect::<Vec<_>>();
+
+                    quote! {
+                        #[allow(unreachable_patterns)]
+                        let #field_ident = match (
+                            match self_variant {
+                                #(#ident::#variants_with_field { #field_ident, .. })|* => Some(#field_ident),
+                                _ => None,
+                            },
+                            match other_variant {
+                                #(#ident::#variants_with_field { #field_ident, .. })|* => Some(#field_ident),
+                                _ => None,
+                            },
+                        ) {
+                            (Some(left), Some(right)) => Some(#field_diff),
+                            _ => None,
+                        };
+                    }
+                });
+
+                (
+                    quote! {
+                        #derive_variant_change_serialize
+                        #edited_attributes
+                        #vis struct #variant_change_ident #variant_change_impl_generics #variant_change_where_clause {
+                            #vis from: &'static str,
+                            #vis to: &'static str,
+                            #(#vis #common_field_idents: Option<#common_field_types>),*
+                        }
+                    },
+                    quote! {
+                        diffus::edit::enm::DetailedEdit<#impl_lifetime, Self, #edited_ident #edited_ty_generics, #variant_change_ident #variant_change_ty_generics>
+                    },
+                    quote! {
+                        #(#common_field_diffs)*
+
+                        diffus::edit::Edit::Change(diffus::edit::enm::DetailedEdit::VariantChanged(
+                            self_variant,
+                            other_variant,
+                            #variant_change_ident {
+                                from: match self_variant { #(#variant_names),* },
+                                to: match other_variant { #(#variant_names),* },
+                                #(#common_field_idents),*
+                            },
+                        ))
+                    },
+                )
+            } else {
+                (
+                    quote! {},
+                    quote! {
+                        diffus::edit::enm::Edit<#impl_lifetime, Self, #edited_ident #edited_ty_generics>
+                    },
+                    quote! {
+                        diffus::edit::Edit::Change(diffus::edit::enm::Edit::VariantChanged(
+                            self_variant, other_variant
+                        ))
+                    },
+                )
+            };
+
             let edit_variants = variants.iter().map(|syn::Variant { ident, fields, .. }| {
-                let edit_fields = edit_fields(&fields, &impl_lifetime);
+                let edit_fields = edit_fields(fields, impl_lifetime);
 
                 match fields {
                     syn::Fields::Named(syn::FieldsNamed { .. }) => {
@@ -174,41 +1319,29 @@ pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                 }
             });
 
-            let has_non_unit_variant = variants.iter().any(|syn::Variant { fields, .. }| {
-                if let syn::Fields::Unit = fields {
-                    false
-                } else {
-                    true
-                }
-            });
-
-            let unit_enum_impl_lifetime = if has_non_unit_variant {
-                Some(impl_lifetime.clone())
-            } else {
-                None
-            };
-
             let variants_matches = variants.iter().map(|syn::Variant { ident: variant_ident, fields, .. }| {
 
-                let field_diffs = fields.iter().enumerate().map(|(i, field)| {
+                let field_diffs = fields.iter().enumerate().filter(|(_, field)| is_diffed(field)).map(|(i, field)| {
                     let self_field_ident = field_ident((i, field), "self_");
                     let other_field_ident = field_ident((i, field), "other_");
 
-                    quote! {
-                        #self_field_ident . diff(& #other_field_ident )
-                    }
+                    field_diff(
+                        field,
+                        quote! { #self_field_ident },
+                        quote! { #other_field_ident },
+                    )
                 });
                 let field_diffs = quote! { #(#field_diffs),* };
 
-                let matches_all_copy = matches_all_copy(&fields);
-                let just_field_idents = field_idents(&fields, "");
-                let self_field_idents = field_idents(&fields, "self_");
-                let other_field_idents = field_idents(&fields, "other_");
+                let matches_all_copy = matches_all_copy(fields);
+                let just_field_idents = field_idents(fields, "");
+                let self_field_idents = field_patterns(fields, "self_");
+                let other_field_idents = field_patterns(fields, "other_");
 
                 match fields {
                     syn::Fields::Named(syn::FieldsNamed { .. }) => {
-                        let self_field_idents = renamed_field_idents(&fields, "self_");
-                        let other_field_idents = renamed_field_idents(&fields, "other_");
+                        let self_field_idents = renamed_field_idents(fields, "self_");
+                        let other_field_idents = renamed_field_idents(fields, "other_");
 
                         quote! {
                             (
@@ -219,7 +1352,7 @@ pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                                     #matches_all_copy,
                                     ( #just_field_idents ) => {
                                         diffus::edit::Edit::Change(
-                                            diffus::edit::enm::Edit::AssociatedChanged(
+                                            #enm_edit::AssociatedChanged(
                                                 #edited_ident::#variant_ident { #just_field_idents }
                                             )
                                         )
@@ -238,7 +1371,7 @@ pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                                     #matches_all_copy,
                                     ( #just_field_idents ) => {
                                         diffus::edit::Edit::Change(
-                                            diffus::edit::enm::Edit::AssociatedChanged(
+                                            #enm_edit::AssociatedChanged(
                                                 #edited_ident::#variant_ident ( #just_field_idents )
                                             )
                                         )
@@ -262,40 +1395,58 @@ pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
             quote! {
                 #derive_serialize
-                #vis enum #edited_ident <#unit_enum_impl_lifetime> where #where_clause {
+                #edited_attributes
+                #vis enum #edited_ident #edited_impl_generics #edited_where_clause {
                     #(#edit_variants),*
                 }
 
-                impl<#impl_lifetime> diffus::Diffable<#impl_lifetime> for #ident <#data_lifetime> where #where_clause {
-                    type Diff = diffus::edit::enm::Edit<#impl_lifetime, Self, #edited_ident <#unit_enum_impl_lifetime>>;
+                #variant_change
+
+                impl #impl_generics diffus::Diffable<#impl_lifetime> for #ident #ty_generics #impl_where_clause {
+                    type Diff = #diff_type;
 
                     fn diff(&#impl_lifetime self, other: &#impl_lifetime Self) -> diffus::edit::Edit<#impl_lifetime, Self> {
                         match (self, other) {
                             #(#variants_matches,)*
-                            (self_variant, other_variant) => diffus::edit::Edit::Change(diffus::edit::enm::Edit::VariantChanged(
-                                self_variant, other_variant
-                            )),
+                            (self_variant, other_variant) => {
+                                #variant_changed
+                            }
                         }
                     }
                 }
             }
         }
         syn::Data::Struct(syn::DataStruct { fields, .. }) => {
-            let edit_fields = edit_fields(&fields, &impl_lifetime);
+            let edit_fields = edit_fields(&fields, impl_lifetime);
             let field_diffs = field_diffs(&fields);
             let field_idents = field_idents(&fields, "");
             let matches_all_copy = matches_all_copy(&fields);
 
             match fields {
                 syn::Fields::Named(_) => {
+                    let edited_helpers = if container_attributes.helpers.is_some() {
+                        Some(edited_helpers(
+                            ident,
+                            vis,
+                            &fields,
+                            impl_lifetime,
+                            quote! { #edited_ident #edited_ty_generics },
+                            quote! { #edited_impl_generics },
+                            quote! { #edited_where_clause },
+                        )?)
+                    } else {
+                        None
+                    };
+
                     quote! {
                         #derive_serialize
-                        #vis struct #edited_ident<#impl_lifetime> where #where_clause {
+                        #edited_attributes
+                        #vis struct #edited_ident #edited_impl_generics #edited_where_clause {
                             #edit_fields
                         }
 
-                        impl<#impl_lifetime> diffus::Diffable<#impl_lifetime> for #ident <#data_lifetime> where #where_clause {
-                            type Diff = #edited_ident<#impl_lifetime>;
+                        impl #impl_generics diffus::Diffable<#impl_lifetime> for #ident #ty_generics #impl_where_clause {
+                            type Diff = #edited_ident #edited_ty_generics;
 
                             fn diff(&#impl_lifetime self, other: &#impl_lifetime Self) -> diffus::edit::Edit<#impl_lifetime, Self> {
                                 match ( #field_diffs ) {
@@ -306,15 +1457,18 @@ pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                                 }
                             }
                         }
+
+                        #edited_helpers
                     }
                 }
                 syn::Fields::Unnamed(_) => {
                     quote! {
                         #derive_serialize
-                        #vis struct #edited_ident<#impl_lifetime> ( #edit_fields ) where #where_clause;
+                        #edited_attributes
+                        #vis struct #edited_ident #edited_impl_generics ( #edit_fields ) #edited_where_clause;
 
-                        impl<#impl_lifetime> diffus::Diffable<#impl_lifetime> for #ident <#data_lifetime> where #where_clause {
-                            type Diff = #edited_ident<#impl_lifetime>;
+                        impl #impl_generics diffus::Diffable<#impl_lifetime> for #ident #ty_generics #impl_where_clause {
+                            type Diff = #edited_ident #edited_ty_generics;
 
                             fn diff(&#impl_lifetime self, other: &#impl_lifetime Self) -> diffus::edit::Edit<#impl_lifetime, Self> {
                                 match ( #field_diffs ) {
@@ -330,9 +1484,10 @@ pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                 syn::Fields::Unit => {
                     quote! {
                         #derive_serialize
-                        #vis struct #edited_ident< > where #where_clause;
+                        #edited_attributes
+                        #vis struct #edited_ident;
 
-                        impl<#impl_lifetime> diffus::Diffable<#impl_lifetime> for #ident< > where #where_clause {
+                        impl #impl_generics diffus::Diffable<#impl_lifetime> for #ident #ty_generics #impl_where_clause {
                             type Diff = #edited_ident;
 
                             fn diff(&#impl_lifetime self, other: &#impl_lifetime Self) -> diffus::edit::Edit<#impl_lifetime, Self> {
@@ -343,6 +1498,15 @@ pub fn derive_diffus(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                 }
             }
         }
-        syn::Data::Union(_) => panic!("union type not supported yet"),
+        syn::Data::Union(_) => unreachable!(),
     })
 }
+
+#[proc_macro_derive(Same, attributes(same))]
+pub fn derive_same(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    proc_macro::TokenStream::from(
+        same::derive_same(input).unwrap_or_else(|error| error.to_compile_error()),
+    )
+}
@@ -0,0 +1,242 @@
+use quote::quote;
+
+use crate::{
+    all_fields, combine_errors, field_ident, mentions, nested_metas, unnamed_field_name, Output,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum SameAttribute {
+    Compare,
+    Id,
+    Skip,
+}
+
+fn try_same_attribute(field: &syn::Field) -> syn::Result<SameAttribute> {
+    let mut attribute = SameAttribute::Compare;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("same")) {
+        for meta in nested_metas(attr)? {
+            let next = match meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("id") => {
+                    SameAttribute::Id
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("skip") => {
+                    SameAttribute::Skip
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "unsupported same field attribute, expected `id` or `skip`",
+                    ))
+                }
+            };
+
+            if attribute != SameAttribute::Compare && attribute != next {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "`id` and `skip` can not be combined on the same field",
+                ));
+            }
+            attribute = next;
+        }
+    }
+
+    Ok(attribute)
+}
+
+// Attributes are checked in `derive_same` before any code is generated.
+fn same_attribute(field: &syn::Field) -> SameAttribute {
+    try_same_attribute(field).expect("same attributes are checked before expansion")
+}
+
+// If any field is marked as `#[same(id)]` only those fields are compared, otherwise all fields
+// that are not marked as `#[same(skip)]` are.
+fn compared_fields(fields: &syn::Fields) -> Vec<bool> {
+    let attributes = fields.iter().map(same_attribute).collect::<Vec<_>>();
+    let has_id = attributes.contains(&SameAttribute::Id);
+
+    attributes
+        .into_iter()
+        .map(|attribute| match attribute {
+            SameAttribute::Id => true,
+            SameAttribute::Compare => !has_id,
+            SameAttribute::Skip => false,
+        })
+        .collect()
+}
+
+// Only the types of compared fields need to be `Same`, so a type parameter used by a skipped
+// field, or by fields next to an `#[same(id)]`, is left unbounded.
+fn compared_field_types(data: &syn::Data) -> Vec<&syn::Type> {
+    let fields = match data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => vec![fields],
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            variants.iter().map(|variant| &variant.fields).collect()
+        }
+        syn::Data::Union(_) => vec![],
+    };
+
+    fields
+        .into_iter()
+        .flat_map(|fields| {
+            fields
+                .iter()
+                .zip(compared_fields(fields))
+                .filter(|(_, compared)| *compared)
+                .map(|(field, _)| &field.ty)
+        })
+        .collect()
+}
+
+fn all_same(comparisons: impl Iterator<Item = Output>) -> Output {
+    let comparisons = comparisons.collect::<Vec<_>>();
+
+    if comparisons.is_empty() {
+        quote! { true }
+    } else {
+        quote! { #(#comparisons)&&* }
+    }
+}
+
+fn variant_pattern(
+    ident: &syn::Ident,
+    variant_ident: &syn::Ident,
+    fields: &syn::Fields,
+    compared: &[bool],
+    prefix: &str,
+) -> Output {
+    let bindings = fields
+        .iter()
+        .enumerate()
+        .zip(compared)
+        .map(|(enumerated_field, compared)| {
+            let binding = if *compared {
+                let binding = field_ident(enumerated_field, prefix);
+                quote! { #binding }
+            } else {
+                quote! { _ }
+            };
+
+            match enumerated_field.1.ident {
+                Some(ref field_ident) => quote! { #field_ident: #binding },
+                None => binding,
+            }
+        });
+
+    match fields {
+        syn::Fields::Named(_) => quote! { #ident::#variant_ident { #(#bindings),* } },
+        syn::Fields::Unnamed(_) => quote! { #ident::#variant_ident ( #(#bindings),* ) },
+        syn::Fields::Unit => quote! { #ident::#variant_ident },
+    }
+}
+
+pub(crate) fn derive_same(input: syn::DeriveInput) -> syn::Result<Output> {
+    let ident = &input.ident;
+
+    if let syn::Data::Union(syn::DataUnion { union_token, .. }) = input.data {
+        return Err(syn::Error::new_spanned(
+            union_token,
+            "derive(Same) is not supported for unions",
+        ));
+    }
+    combine_errors(
+        all_fields(&input.data)
+            .into_iter()
+            .filter_map(|field| try_same_attribute(field).err()),
+    )?;
+
+    let mut generics = input.generics.clone();
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|syn::TypeParam { ident, .. }| ident)
+        .collect::<Vec<_>>();
+    generics.make_where_clause().predicates.extend(
+        compared_field_types(&input.data)
+            .into_iter()
+            .filter(|ty| {
+                type_params
+                    .iter()
+                    .any(|type_param| mentions(quote! { #ty }, type_param))
+            })
+            .map::<syn::WherePredicate, _>(|ty| syn::parse_quote! { #ty: diffus::Same }),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match input.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => {
+            let compared = compared_fields(fields);
+
+            all_same(
+                fields
+                    .iter()
+                    .enumerate()
+                    .zip(compared)
+                    .filter(|(_, compared)| *compared)
+                    .map(|((index, field), _)| {
+                        let field_name = match field.ident {
+                            Some(ref ident) => quote! { #ident },
+                            None => {
+                                let ident = unnamed_field_name(index);
+
+                                quote! { #ident }
+                            }
+                        };
+
+                        quote! { diffus::Same::same(&self.#field_name, &other.#field_name) }
+                    }),
+            )
+        }
+        syn::Data::Enum(syn::DataEnum { ref variants, .. }) => {
+            let variants_matches = variants.iter().map(
+                |syn::Variant {
+                     ident: variant_ident,
+                     fields,
+                     ..
+                 }| {
+                    let compared = compared_fields(fields);
+
+                    let self_pattern =
+                        variant_pattern(ident, variant_ident, fields, &compared, "self_");
+                    let other_pattern =
+                        variant_pattern(ident, variant_ident, fields, &compared, "other_");
+
+                    let comparisons = all_same(
+                        fields
+                            .iter()
+                            .enumerate()
+                            .zip(&compared)
+                            .filter(|(_, compared)| **compared)
+                            .map(|(enumerated_field, _)| {
+                                let self_field_ident = field_ident(enumerated_field, "self_");
+                                let other_field_ident = field_ident(enumerated_field, "other_");
+
+                                quote! { diffus::Same::same(#self_field_ident, #other_field_ident) }
+                            }),
+                    );
+
+                    quote! {
+                        ( #self_pattern, #other_pattern ) => #comparisons
+                    }
+                },
+            );
+
+            quote! {
+                match (self, other) {
+                    #(#variants_matches,)*
+                    _ => false,
+                }
+            }
+        }
+        syn::Data::Union(_) => unreachable!(),
+    };
+
+    Ok(quote! {
+        impl #impl_generics diffus::Same for #ident #ty_generics #where_clause {
+            #[allow(unreachable_patterns)]
+            fn same(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,215 @@
+use crate::{
+    edit::{self, bag},
+    Diffable, Same,
+};
+use std::collections::{btree_map, BTreeMap};
+
+/// A multiset, counting how many times each element occurs. Its diff reports per-element count
+/// changes, regardless of the order the elements were inserted in.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bag<T: Ord> {
+    counts: BTreeMap<T, usize>,
+    len: usize,
+}
+
+impl<T: Ord> Bag<T> {
+    pub fn new() -> Self {
+        Self {
+            counts: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Adds one occurrence of `value`, returning its new count.
+    pub fn insert(&mut self, value: T) -> usize {
+        self.len += 1;
+        let count = self.counts.entry(value).or_insert(0);
+        *count += 1;
+        *count
+    }
+
+    /// Removes one occurrence of `value`, returning whether it was present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        match self.counts.get_mut(value) {
+            Some(count) => {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(value);
+                }
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn count(&self, value: &T) -> usize {
+        self.counts.get(value).copied().unwrap_or(0)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.counts.contains_key(value)
+    }
+
+    /// The total number of occurrences.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The distinct elements with their counts, in order.
+    pub fn iter(&self) -> btree_map::Iter<'_, T, usize> {
+        self.counts.iter()
+    }
+}
+
+impl<T: Ord> Default for Bag<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for Bag<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord> std::iter::FromIterator<T> for Bag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bag = Self::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+impl<'b, T: Ord> IntoIterator for &'b Bag<T> {
+    type Item = (&'b T, &'b usize);
+    type IntoIter = btree_map::Iter<'b, T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub(crate) fn counts<'a, T: Ord + 'a>(
+    values: impl Iterator<Item = &'a T>,
+) -> BTreeMap<&'a T, usize> {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+}
+
+// The count change of every element in either multiset, or `None` if all counts are unchanged.
+pub(crate) fn diff_counts<'a, T: Ord>(
+    left: &BTreeMap<&'a T, usize>,
+    right: &BTreeMap<&'a T, usize>,
+) -> Option<bag::Diff<'a, T>> {
+    let diff = left
+        .iter()
+        .map(|(value, count)| {
+            (
+                *value,
+                bag::Count::new(*count, right.get(value).copied().unwrap_or(0)),
+            )
+        })
+        .chain(
+            right
+                .iter()
+                .filter(|(value, _)| !left.contains_key(*value))
+                .map(|(value, count)| (*value, bag::Count::new(0, *count))),
+        )
+        .collect::<bag::Diff<'a, T>>();
+
+    if diff.values().all(bag::Count::is_copy) {
+        None
+    } else {
+        Some(diff)
+    }
+}
+
+/// Diffs two slices as multisets, reporting how the count of each element changed regardless of
+/// order.
+pub fn diff_unordered<'a, T: Ord>(left: &'a [T], right: &'a [T]) -> bag::Edit<'a, T> {
+    match diff_counts(&counts(left.iter()), &counts(right.iter())) {
+        Some(diff) => bag::Edit::Change(diff),
+        None => bag::Edit::Copy(left),
+    }
+}
+
+impl<'a, T: Ord + 'a> Diffable<'a> for Bag<T> {
+    type Diff = bag::Diff<'a, T>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let borrowed = |bag: &'a Self| {
+            bag.counts
+                .iter()
+                .map(|(value, count)| (value, *count))
+                .collect()
+        };
+
+        match diff_counts(&borrowed(self), &borrowed(other)) {
+            Some(diff) => edit::Edit::Change(diff),
+            None => edit::Edit::Copy(self),
+        }
+    }
+}
+
+impl<T: Ord> Same for Bag<T> {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bag() {
+        let left: Bag<_> = vec!["a", "b", "b", "c"].into_iter().collect();
+        let right: Bag<_> = vec!["b", "c", "c", "d", "b"].into_iter().collect();
+
+        assert_eq!(left.len(), 4);
+        assert_eq!(left.count(&"b"), 2);
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(
+            diff.iter()
+                .map(|(value, count)| (**value, *count))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", bag::Count::Decrease { from: 1, to: 0 }),
+                ("b", bag::Count::Copy(2)),
+                ("c", bag::Count::Increase { from: 1, to: 2 }),
+                ("d", bag::Count::Increase { from: 0, to: 1 }),
+            ]
+        );
+
+        assert!(left.diff(&left.clone()).is_copy());
+    }
+
+    #[test]
+    fn unordered() {
+        let left = vec![3, 1, 2, 1];
+        let right = vec![1, 2, 1, 3];
+
+        assert!(diff_unordered(&left, &right).is_copy());
+
+        let right = vec![1, 2, 3];
+        assert_eq!(
+            diff_unordered(&left, &right).change().unwrap()[&1],
+            bag::Count::Decrease { from: 2, to: 1 }
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{edit, Diffable};
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 
 fn diff_borrowable<'a, T, C, D>(left: &'a C, right: &'a C) -> edit::Edit<'a, C>
 where
@@ -32,7 +32,15 @@ borrow_impl! {
     Box, Rc, Arc
 }
 
-impl<'a, T: Diffable<'a> + ?Sized + 'a> Diffable<'a> for &'a T {
+impl<'a, 'b: 'a, B: Diffable<'a> + ToOwned + ?Sized + 'a> Diffable<'a> for Cow<'b, B> {
+    type Diff = B::Diff;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        diff_borrowable::<B, _, _>(self, other)
+    }
+}
+
+impl<'a, 'b: 'a, T: Diffable<'a> + ?Sized + 'a> Diffable<'a> for &'b T {
     type Diff = T::Diff;
 
     fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
@@ -75,6 +83,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn reference_example() {
         let left = 13;
         let right = 37;
@@ -83,4 +92,23 @@ mod tests {
             assert_eq!(diff, (&13, &37));
         }
     }
+
+    #[test]
+    fn cow_example() {
+        use crate::edit::string;
+
+        let left: Cow<str> = Cow::Borrowed("abc");
+        let right: Cow<str> = Cow::Owned("abd".to_owned());
+
+        assert!(left.diff(&Cow::Owned("abc".to_owned())).is_copy());
+        assert_eq!(
+            left.diff(&right).change(),
+            Some(&vec![
+                string::Edit::Copy('a'),
+                string::Edit::Copy('b'),
+                string::Edit::Remove('c'),
+                string::Edit::Insert('d'),
+            ])
+        );
+    }
 }
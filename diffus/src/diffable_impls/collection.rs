@@ -1,5 +1,5 @@
 use crate::{
-    edit::{self, collection},
+    edit::{self, bag, collection},
     Diffable, Same,
 };
 
@@ -9,7 +9,7 @@ macro_rules! collection_impl {
             impl<'a, T: Same + Diffable<'a> + 'a> Diffable<'a> for $typ<T> {
                 type Diff = Vec<collection::Edit<'a, T, T::Diff>>;
 
-                fn diff(&'a self, other: &'a Self) -> edit::Edit<Self> {
+                fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
 
                     let s = crate::lcs::lcs_post_change(
                         crate::lcs::lcs(
@@ -34,7 +34,53 @@ macro_rules! collection_impl {
 
 use std::collections::{BinaryHeap, LinkedList, VecDeque};
 collection_impl! {
-    BinaryHeap, LinkedList, Vec, VecDeque
+    LinkedList, Vec, VecDeque
+}
+
+// A heap iterates in an arbitrary order, so it is diffed as a multiset.
+impl<'a, T: Ord + 'a> Diffable<'a> for BinaryHeap<T> {
+    type Diff = bag::Diff<'a, T>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        match crate::bag::diff_counts(
+            &crate::bag::counts(self.iter()),
+            &crate::bag::counts(other.iter()),
+        ) {
+            Some(diff) => edit::Edit::Change(diff),
+            None => edit::Edit::Copy(self),
+        }
+    }
+}
+
+impl<'a, T: Same + Diffable<'a> + 'a> Diffable<'a> for [T] {
+    type Diff = Vec<collection::Edit<'a, T, T::Diff>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let s = crate::lcs::lcs_post_change(crate::lcs::lcs(
+            || self.iter(),
+            || other.iter(),
+            self.len(),
+            other.len(),
+        ))
+        .collect::<Vec<_>>();
+
+        if s.iter().all(collection::Edit::is_copy) {
+            edit::Edit::Copy(self)
+        } else {
+            edit::Edit::Change(s)
+        }
+    }
+}
+
+impl<'a, T: Same + Diffable<'a> + 'a, const N: usize> Diffable<'a> for [T; N] {
+    type Diff = Vec<collection::Edit<'a, T, T::Diff>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        match self[..].diff(&other[..]) {
+            edit::Edit::Copy(_) => edit::Edit::Copy(self),
+            edit::Edit::Change(diff) => edit::Edit::Change(diff),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -71,4 +117,63 @@ mod tests {
             unreachable!()
         }
     }
+
+    #[test]
+    fn nested() {
+        let left = vec![vec!["a".to_owned(), "b".to_owned()], vec!["c".to_owned()]];
+        let right = vec![vec!["a".to_owned(), "b".to_owned()], vec!["d".to_owned()]];
+
+        if let edit::Edit::Change(diff) = left.diff(&right) {
+            use collection::Edit::*;
+
+            assert_eq!(
+                diff,
+                vec![Copy(&left[0]), Remove(&left[1]), Insert(&right[1]),]
+            );
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn slice() {
+        let left: &[u32] = &[1, 2, 3];
+        let right: &[u32] = &[1, 2, 4];
+
+        use collection::Edit::*;
+
+        assert_eq!(
+            left.diff(right).change(),
+            Some(&vec![Copy(&1), Copy(&2), Remove(&3), Insert(&4)])
+        );
+        assert!(left.diff(left).is_copy());
+    }
+
+    #[test]
+    fn array() {
+        use collection::Edit::*;
+
+        assert_eq!(
+            [1, 2, 3].diff(&[1, 2, 4]).change(),
+            Some(&vec![Copy(&1), Copy(&2), Remove(&3), Insert(&4)])
+        );
+        assert!([1, 2, 3].diff(&[1, 2, 3]).is_copy());
+    }
+
+    #[test]
+    fn binary_heap() {
+        let left: BinaryHeap<_> = vec![5, 1, 3, 3].into_iter().collect();
+        let right: BinaryHeap<_> = vec![3, 1, 5, 3].into_iter().collect();
+
+        assert!(left.diff(&right).is_copy());
+
+        let right: BinaryHeap<_> = vec![3, 5, 7].into_iter().collect();
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(diff[&1], bag::Count::Decrease { from: 1, to: 0 });
+        assert_eq!(diff[&3], bag::Count::Decrease { from: 2, to: 1 });
+        assert_eq!(diff[&5], bag::Count::Copy(1));
+        assert_eq!(diff[&7], bag::Count::Increase { from: 0, to: 1 });
+    }
 }
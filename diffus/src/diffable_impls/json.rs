@@ -0,0 +1,134 @@
+use crate::{
+    diffable_impls::map::map_edits,
+    edit::{self, collection, enm, json},
+    lcs, Diffable, Same,
+};
+use serde_json::Value;
+
+impl<'a> Diffable<'a> for Value {
+    type Diff = enm::Edit<'a, Self, json::Diff<'a>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let diff = match (self, other) {
+            (Value::Null, Value::Null) => None,
+            (Value::Bool(_), Value::Bool(_))
+            | (Value::Number(_), Value::Number(_))
+            | (Value::String(_), Value::String(_)) => {
+                if self.same(other) {
+                    None
+                } else {
+                    Some(json::Diff::Scalar(self, other))
+                }
+            }
+            (Value::Array(left), Value::Array(right)) => {
+                let edits = lcs::lcs_post_change(lcs::lcs(
+                    || left.iter(),
+                    || right.iter(),
+                    left.len(),
+                    right.len(),
+                ))
+                .collect::<Vec<_>>();
+
+                if edits.iter().all(collection::Edit::is_copy) {
+                    None
+                } else {
+                    Some(json::Diff::Array(edits))
+                }
+            }
+            (Value::Object(left), Value::Object(right)) => {
+                let edits = map_edits(
+                    left,
+                    right,
+                    |key| left.contains_key(key),
+                    |key| right.get(key),
+                )
+                .collect::<std::collections::BTreeMap<_, _>>();
+
+                if edits.values().all(|edit| edit.is_copy()) {
+                    None
+                } else {
+                    Some(json::Diff::Object(edits))
+                }
+            }
+            _ => return edit::Edit::Change(enm::Edit::VariantChanged(self, other)),
+        };
+
+        match diff {
+            Some(diff) => edit::Edit::Change(enm::Edit::AssociatedChanged(diff)),
+            None => edit::Edit::Copy(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn numbers() {
+        assert!(json!(1).diff(&json!(1.0)).is_copy());
+        assert!(json!(-1).diff(&json!(18446744073709551615u64)).is_change());
+        assert!(json!({"a": [1, 2.5]}).same(&json!({"a": [1.0, 2.5]})));
+        assert!(json!(9007199254740993u64)
+            .diff(&json!(9007199254740992.0))
+            .is_change());
+        assert!(json!(-9007199254740992i64).same(&json!(-9007199254740992.0)));
+        assert!(!json!(1).same(&json!(1.5)));
+    }
+
+    #[test]
+    fn variant_changed() {
+        let left = json!({"a": 1});
+        let right = json!({"a": "1"});
+
+        let diff = left.diff(&right);
+        if let Some(enm::Edit::AssociatedChanged(json::Diff::Object(edits))) = diff.change() {
+            assert_eq!(
+                edits[&"a".to_owned()].change(),
+                Some(&enm::Edit::VariantChanged(&left["a"], &right["a"]))
+            );
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn patch() {
+        let left = json!({
+            "name": "diffus",
+            "tags": ["a", "b", "c"],
+            "a/b": {"c": true},
+            "removed": null,
+        });
+        let right = json!({
+            "name": "diffus",
+            "tags": ["a", "x", "c", "d"],
+            "a/b": {"c": false},
+            "added": 1,
+        });
+
+        let diff = left.diff(&right);
+
+        assert_eq!(
+            diff.json_patch(),
+            json!([
+                {"op": "replace", "path": "/a~1b/c", "value": false},
+                {"op": "add", "path": "/added", "value": 1},
+                {"op": "remove", "path": "/removed"},
+                {"op": "remove", "path": "/tags/1"},
+                {"op": "add", "path": "/tags/1", "value": "x"},
+                {"op": "add", "path": "/tags/3", "value": "d"},
+            ])
+        );
+        assert_eq!(
+            diff.paths(),
+            vec!["/a~1b/c", "/added", "/removed", "/tags/1", "/tags/1", "/tags/3"]
+        );
+        assert_eq!(left.diff(&left).json_patch(), json!([]));
+        assert_eq!(
+            json!(1).diff(&json!([])).json_patch(),
+            json!([{"op": "replace", "path": "", "value": []}])
+        );
+    }
+}
@@ -1,28 +1,74 @@
 use crate::{
-    edit::{map, Edit},
+    edit::{as_map, map, Edit},
     Diffable,
 };
 
-macro_rules! map_impl {
-    ($(($typ:ident, $key_constraint:ident)),*) => {
-        $(
-            impl<'a, K: Eq + $key_constraint + 'a, V: Diffable<'a> + 'a> Diffable<'a> for $typ<K, V> {
-                type Diff = $typ<&'a K, map::Edit<'a, V>>;
+// The edit of every key in either map: the keys only in `right` are inserted, then the keys only in
+// `left` are removed and the keys in both are diffed.
+pub(crate) fn map_edits<'a, K: 'a, V: Diffable<'a> + 'a>(
+    left: impl IntoIterator<Item = (&'a K, &'a V)> + Clone,
+    right: impl IntoIterator<Item = (&'a K, &'a V)>,
+    left_contains: impl Fn(&K) -> bool,
+    right_get: impl Fn(&K) -> Option<&'a V> + Copy,
+) -> impl Iterator<Item = (&'a K, map::Edit<'a, V>)> {
+    let unique_other = right
+        .into_iter()
+        .filter(move |(k, _)| !left_contains(k))
+        .map(|(k, v)| (k, map::Edit::Insert(v)));
+    let unique_self = left
+        .clone()
+        .into_iter()
+        .filter(move |(k, _)| right_get(k).is_none())
+        .map(|(k, v)| (k, map::Edit::Remove(v)));
+    let intersection = left
+        .into_iter()
+        .filter_map(move |(k, v)| Some((k, v.diff(right_get(k)?).into())));
 
-                fn diff(&'a self, other: &'a Self) -> Edit<Self> {
-                    let intersection = self
-                        .iter()
-                        .filter_map(|(k, v)| Some((k, (v, other.get(k)?))));
+    unique_other.chain(unique_self).chain(intersection)
+}
 
-                    let unique_self = self.iter().filter(|(k, _)| !other.contains_key(*k));
+// Like `map_edits`, but in the order of `left` followed by the keys only in `right`, for maps whose
+// diff is a list of entries.
+#[cfg(any(feature = "serde_yaml-impl", feature = "serde-value"))]
+pub(crate) fn left_ordered_map_edits<'a, K: 'a, V: Diffable<'a> + 'a>(
+    left: impl IntoIterator<Item = (&'a K, &'a V)>,
+    right: impl IntoIterator<Item = (&'a K, &'a V)>,
+    left_contains: impl Fn(&K) -> bool,
+    right_get: impl Fn(&K) -> Option<&'a V>,
+) -> impl Iterator<Item = (&'a K, map::Edit<'a, V>)> {
+    left.into_iter()
+        .map(move |(k, v)| match right_get(k) {
+            Some(other_v) => (k, v.diff(other_v).into()),
+            None => (k, map::Edit::Remove(v)),
+        })
+        .chain(
+            right
+                .into_iter()
+                .filter(move |(k, _)| !left_contains(k))
+                .map(|(k, v)| (k, map::Edit::Insert(v))),
+        )
+}
 
-                    let unique_other = other.iter().filter(|(k, _)| !self.contains_key(*k));
+macro_rules! map_impl {
+    (@new $typ:ident, $map:expr) => {
+        $typ::new()
+    };
+    (@new $typ:ident, $map:expr, $hasher:ident) => {
+        $typ::with_hasher($map.hasher().clone())
+    };
+    ($(($typ:ident, $key_constraint:ident $(, $hasher:ident)?)),*) => {
+        $(
+            impl<'a, K: Eq + $key_constraint + 'a, V: Diffable<'a> + 'a $(, $hasher: BuildHasher + Clone + 'a)?> Diffable<'a> for $typ<K, V $(, $hasher)?> {
+                type Diff = $typ<&'a K, map::Edit<'a, V> $(, $hasher)?>;
 
-                    let value_diffs = unique_other
-                        .map(|(k, v)| (k, map::Edit::Insert(v)))
-                        .chain(unique_self.map(|(k, v)| (k, map::Edit::Remove(v))))
-                        .chain(intersection.map(|(k, (self_v, other_v))| (k, self_v.diff(other_v).into())))
-                        .collect::<$typ<_, _>>();
+                fn diff(&'a self, other: &'a Self) -> Edit<'a, Self> {
+                    let mut value_diffs = map_impl!(@new $typ, self $(, $hasher)?);
+                    value_diffs.extend(map_edits(
+                        self,
+                        other,
+                        |k| self.contains_key(k),
+                        |k| other.get(k),
+                    ));
 
                     if value_diffs.values().any(|v| !v.is_copy()) {
                         Edit::Change(value_diffs)
@@ -37,17 +83,47 @@ macro_rules! map_impl {
 
 use std::{
     collections::{BTreeMap, HashMap},
-    hash::Hash,
+    hash::{BuildHasher, Hash},
 };
 map_impl! {
     (BTreeMap, Ord),
-    (HashMap, Hash)
+    (HashMap, Hash, S)
 }
 
 #[cfg(feature = "indexmap-impl")]
 use indexmap::IndexMap;
 #[cfg(feature = "indexmap-impl")]
-map_impl! { (IndexMap, Hash) }
+map_impl! { (IndexMap, Hash, S) }
+
+#[cfg(feature = "hashbrown-impl")]
+use hashbrown::HashMap as HashbrownMap;
+#[cfg(feature = "hashbrown-impl")]
+map_impl! { (HashbrownMap, Hash, S) }
+
+/// Diffs two association lists as maps, matching values by key instead of by position. When a key
+/// occurs more than once, its last value is used.
+pub fn diff_as_map<'a, K: Ord + 'a, V: Diffable<'a> + 'a>(
+    left: &'a [(K, V)],
+    right: &'a [(K, V)],
+) -> as_map::Edit<'a, K, V> {
+    let as_map = |list: &'a [(K, V)]| list.iter().map(|(k, v)| (k, v)).collect::<BTreeMap<_, _>>();
+    let left_map = as_map(left);
+    let right_map = as_map(right);
+
+    let value_diffs = map_edits(
+        left_map.iter().map(|(k, v)| (*k, *v)),
+        right_map.iter().map(|(k, v)| (*k, *v)),
+        |k| left_map.contains_key(k),
+        |k| right_map.get(k).copied(),
+    )
+    .collect::<as_map::Diff<'a, K, V>>();
+
+    if value_diffs.values().any(|v| !v.is_copy()) {
+        as_map::Edit::Change(value_diffs)
+    } else {
+        as_map::Edit::Copy(left)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -69,4 +145,65 @@ mod tests {
             unreachable!()
         }
     }
+
+    #[test]
+    fn custom_hasher() {
+        use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+
+        type Map = HashMap<u32, u32, BuildHasherDefault<DefaultHasher>>;
+
+        let left: Map = [(1, 1), (2, 2)].iter().cloned().collect();
+        let right: Map = [(1, 1), (2, 3)].iter().cloned().collect();
+
+        let diff: HashMap<&u32, map::Edit<u32>, BuildHasherDefault<DefaultHasher>> =
+            left.diff(&right).change().unwrap().clone();
+
+        assert!(diff[&1].is_copy());
+        assert_eq!(diff[&2].change().unwrap(), &(&2, &3));
+    }
+
+    #[cfg(feature = "hashbrown-impl")]
+    #[test]
+    fn hashbrown() {
+        let left: HashbrownMap<_, _> = [(1, 1), (2, 2)].iter().cloned().collect();
+        let right: HashbrownMap<_, _> = [(1, 1), (3, 3)].iter().cloned().collect();
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff[&1].is_copy());
+        assert!(diff[&2].is_remove());
+        assert_eq!(diff[&3].insert().unwrap(), &3);
+    }
+
+    #[cfg(feature = "indexmap-impl")]
+    #[test]
+    fn indexmap_order() {
+        let left: IndexMap<_, _> = [(1, 1), (2, 2), (3, 3), (4, 4)].iter().cloned().collect();
+        let right: IndexMap<_, _> = [(1, 1), (3, 4), (5, 5)].iter().cloned().collect();
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(
+            diff.keys().map(|k| **k).collect::<Vec<_>>(),
+            vec![5, 2, 4, 1, 3]
+        );
+    }
+
+    #[test]
+    fn as_map() {
+        let left = vec![("a", 1), ("b", 2), ("c", 3)];
+        let right = vec![("c", 3), ("b", 4), ("a", 1)];
+
+        let diff = diff_as_map(&left, &right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(diff.len(), 3);
+        assert!(diff[&"a"].is_copy());
+        assert_eq!(diff[&"b"].change().unwrap(), &(&2, &4));
+        assert!(diff[&"c"].is_copy());
+
+        assert!(diff_as_map(&left, &[("c", 3), ("a", 1), ("b", 2)]).is_copy());
+    }
 }
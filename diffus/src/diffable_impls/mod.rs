@@ -1,7 +1,18 @@
 pub mod borrow;
 pub mod collection;
+#[cfg(feature = "serde_json-impl")]
+pub mod json;
 pub mod map;
 pub mod option;
+pub mod path;
 pub mod primitives;
+pub mod range;
+pub mod result;
 pub mod set;
 pub mod string;
+pub mod time;
+#[cfg(feature = "toml-impl")]
+pub mod toml;
+pub mod tuple;
+#[cfg(feature = "serde_yaml-impl")]
+pub mod yaml;
@@ -1,15 +1,15 @@
 use crate::{
-    edit::{self, enm},
+    edit::{self, enm, option},
     Diffable,
 };
 
 impl<'a, T: Diffable<'a> + 'a> Diffable<'a> for Option<T> {
     type Diff = enm::Edit<'a, Self, T::Diff>;
 
-    fn diff(&'a self, other: &'a Self) -> edit::Edit<Self> {
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
         match (self, other) {
             (None, None) => edit::Edit::Copy(self),
-            (Some(a), Some(b)) => match a.diff(&b) {
+            (Some(a), Some(b)) => match a.diff(b) {
                 edit::Edit::Copy(_) => edit::Edit::Copy(self),
                 edit::Edit::Change(diff) => edit::Edit::Change(enm::Edit::AssociatedChanged(diff)),
             },
@@ -18,6 +18,23 @@ impl<'a, T: Diffable<'a> + 'a> Diffable<'a> for Option<T> {
     }
 }
 
+/// Diffs two options into a `Set`, `Unset` or `Changed` edit rather than the variant change of
+/// `Option`'s `Diffable` implementation.
+pub fn diff_option<'a, T: Diffable<'a> + 'a>(
+    left: &'a Option<T>,
+    right: &'a Option<T>,
+) -> option::Edit<'a, T> {
+    match (left, right) {
+        (None, None) => option::Edit::Copy(left),
+        (None, Some(value)) => option::Edit::Set(value),
+        (Some(value), None) => option::Edit::Unset(value),
+        (Some(a), Some(b)) => match a.diff(b) {
+            edit::Edit::Copy(_) => option::Edit::Copy(left),
+            edit::Edit::Change(diff) => option::Edit::Changed(diff),
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +60,20 @@ mod tests {
             unreachable!();
         }
     }
+
+    #[test]
+    fn diff_option_copy() {
+        assert!(diff_option(&None as &Option<u32>, &None).is_copy());
+        assert!(diff_option(&Some(3), &Some(3)).is_copy());
+    }
+
+    #[test]
+    fn diff_option_set_unset_changed() {
+        assert_eq!(diff_option(&None, &Some(3)), option::Edit::Set(&3));
+        assert_eq!(diff_option(&Some(3), &None), option::Edit::Unset(&3));
+        assert_eq!(
+            diff_option(&Some(1), &Some(2)),
+            option::Edit::Changed((&1, &2))
+        );
+    }
 }
@@ -0,0 +1,81 @@
+use crate::{
+    edit::{self, collection},
+    lcs, Diffable,
+};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+impl<'a> Diffable<'a> for Path {
+    type Diff = Vec<collection::Edit<'a, OsStr, <OsStr as Diffable<'a>>::Diff>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let left = self
+            .components()
+            .map(|component| component.as_os_str())
+            .collect::<Vec<_>>();
+        let right = other
+            .components()
+            .map(|component| component.as_os_str())
+            .collect::<Vec<_>>();
+
+        let s = lcs::lcs_post_change(lcs::lcs(
+            || left.iter().cloned(),
+            || right.iter().cloned(),
+            left.len(),
+            right.len(),
+        ))
+        .collect::<Vec<_>>();
+
+        if s.iter().all(collection::Edit::is_copy) {
+            edit::Edit::Copy(self)
+        } else {
+            edit::Edit::Change(s)
+        }
+    }
+}
+
+impl<'a> Diffable<'a> for PathBuf {
+    type Diff = <Path as Diffable<'a>>::Diff;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        match self.as_path().diff(other.as_path()) {
+            edit::Edit::Change(diff) => edit::Edit::Change(diff),
+            edit::Edit::Copy(_) => edit::Edit::Copy(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path() {
+        let left = Path::new("/usr/local/bin");
+
+        assert!(left.diff(Path::new("/usr/local/bin/")).is_copy());
+
+        use collection::Edit::*;
+
+        assert_eq!(
+            left.diff(Path::new("/usr/bin/env")).change(),
+            Some(&vec![
+                Copy(OsStr::new("/")),
+                Copy(OsStr::new("usr")),
+                Remove(OsStr::new("local")),
+                Copy(OsStr::new("bin")),
+                Insert(OsStr::new("env")),
+            ])
+        );
+    }
+
+    #[test]
+    fn path_buf() {
+        let left = PathBuf::from("a/b");
+
+        assert!(left.diff(&PathBuf::from("a/b")).is_copy());
+        assert!(left.diff(&PathBuf::from("a/c")).is_change());
+    }
+}
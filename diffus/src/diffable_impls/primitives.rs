@@ -6,7 +6,7 @@ macro_rules! primitive_impl {
             impl<'a> Diffable<'a> for $typ {
                 type Diff = (&'a $typ, &'a $typ);
 
-                fn diff(&'a self, other: &'a Self) -> edit::Edit<Self> {
+                fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
                     use crate::Same;
                     if self.same(other) {
                         edit::Edit::Copy(self)
@@ -21,8 +21,87 @@ macro_rules! primitive_impl {
 
 primitive_impl! { i64, i32, i16, i8, u64, u32, u16, u8, char, bool, isize, usize, f32, f64, () }
 
+primitive_impl! { i128, u128 }
+
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+primitive_impl! {
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize
+}
+
+use std::{
+    cmp::Ordering,
+    ffi::{OsStr, OsString},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+};
+primitive_impl! {
+    Ordering, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, OsStr, OsString
+}
+
+impl<'a, T: Diffable<'a> + 'a> Diffable<'a> for std::num::Wrapping<T> {
+    type Diff = T::Diff;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        match self.0.diff(&other.0) {
+            edit::Edit::Copy(_) => edit::Edit::Copy(self),
+            edit::Edit::Change(diff) => edit::Edit::Change(diff),
+        }
+    }
+}
+
+impl<'a, T: ?Sized + 'a> Diffable<'a> for std::marker::PhantomData<T> {
+    type Diff = ();
+
+    fn diff(&'a self, _other: &'a Self) -> edit::Edit<'a, Self> {
+        edit::Edit::Copy(self)
+    }
+}
+
 #[cfg(feature = "uuid-impl")]
 primitive_impl! { uuid::Uuid }
 
 #[cfg(feature = "snake_case-impl")]
 primitive_impl! { snake_case::SnakeCase }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_and_non_zero() {
+        assert_eq!(1i128.diff(&2i128).change(), Some(&(&1i128, &2i128)));
+
+        let left = NonZeroU32::new(1).unwrap();
+        let right = NonZeroU32::new(2).unwrap();
+
+        assert!(left.diff(&left.clone()).is_copy());
+        assert_eq!(left.diff(&right).change(), Some(&(&left, &right)));
+    }
+
+    #[test]
+    fn wrapping() {
+        use std::num::Wrapping;
+
+        assert!(Wrapping(1u8).diff(&Wrapping(1u8)).is_copy());
+        assert_eq!(Wrapping(1u8).diff(&Wrapping(2u8)).change(), Some(&(&1, &2)));
+    }
+
+    #[test]
+    fn addresses() {
+        let left: IpAddr = "127.0.0.1".parse().unwrap();
+        let right: IpAddr = "::1".parse().unwrap();
+
+        assert!(left.diff(&left.clone()).is_copy());
+        assert!(left.diff(&right).is_change());
+    }
+
+    #[test]
+    fn phantom_data() {
+        assert!(std::marker::PhantomData::<String>
+            .diff(&std::marker::PhantomData)
+            .is_copy());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{edit, Diffable};
+use std::ops::Range;
+
+impl<'a, T: Diffable<'a> + 'a> Diffable<'a> for Range<T> {
+    type Diff = (edit::Edit<'a, T>, edit::Edit<'a, T>);
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let start = self.start.diff(&other.start);
+        let end = self.end.diff(&other.end);
+
+        if start.is_copy() && end.is_copy() {
+            edit::Edit::Copy(self)
+        } else {
+            edit::Edit::Change((start, end))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range() {
+        assert!((1..3).diff(&(1..3)).is_copy());
+        assert_eq!(
+            (1..3).diff(&(1..4)).change(),
+            Some(&(edit::Edit::Copy(&1), edit::Edit::Change((&3, &4))))
+        );
+    }
+}
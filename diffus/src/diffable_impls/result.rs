@@ -0,0 +1,66 @@
+use crate::{
+    edit::{self, enm},
+    Diffable,
+};
+
+impl<'a, T: Diffable<'a> + 'a, E: Diffable<'a> + 'a> Diffable<'a> for Result<T, E> {
+    type Diff = enm::Edit<'a, Self, Result<T::Diff, E::Diff>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        match (self, other) {
+            (Ok(a), Ok(b)) => match a.diff(b) {
+                edit::Edit::Copy(_) => edit::Edit::Copy(self),
+                edit::Edit::Change(diff) => {
+                    edit::Edit::Change(enm::Edit::AssociatedChanged(Ok(diff)))
+                }
+            },
+            (Err(a), Err(b)) => match a.diff(b) {
+                edit::Edit::Copy(_) => edit::Edit::Copy(self),
+                edit::Edit::Change(diff) => {
+                    edit::Edit::Change(enm::Edit::AssociatedChanged(Err(diff)))
+                }
+            },
+            _ => edit::Edit::Change(enm::Edit::VariantChanged(self, other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_copy() {
+        let ok: Result<u32, String> = Ok(3);
+        let err: Result<u32, String> = Err("error".to_owned());
+
+        assert!(ok.diff(&Ok(3)).is_copy());
+        assert!(err.diff(&Err("error".to_owned())).is_copy());
+    }
+
+    #[test]
+    fn variant_changed() {
+        let left: Result<u32, u32> = Ok(3);
+        let right = Err(3);
+
+        if let Some(enm::Edit::VariantChanged(&Ok(3), &Err(3))) = left.diff(&right).change() {
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn associate_change() {
+        let left: Result<u32, u32> = Ok(1);
+        if let Some(enm::Edit::AssociatedChanged(Ok((&1, &2)))) = left.diff(&Ok(2)).change() {
+        } else {
+            unreachable!();
+        }
+
+        let left: Result<u32, u32> = Err(1);
+        if let Some(enm::Edit::AssociatedChanged(Err((&1, &2)))) = left.diff(&Err(2)).change() {
+        } else {
+            unreachable!();
+        }
+    }
+}
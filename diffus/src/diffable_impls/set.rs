@@ -1,28 +1,53 @@
 use crate::{
-    edit::{set, Edit},
+    edit::{as_set, set, Edit},
     Diffable,
 };
 
-macro_rules! set_impl {
-    ($(($typ:ident, $key_constraint:ident, $diff_type:ident)),*) => {
-        $(
-            impl<'a, K: Diffable<'a> + Eq + $key_constraint + 'a> Diffable<'a> for $typ<K> {
-                type Diff = $diff_type<&'a K, set::Edit<'a, K>>;
-
-                fn diff(&'a self, other: &'a Self) -> Edit<Self> {
-                    let intersection = self
-                        .iter()
-                        .filter(|k| other.contains(*k));
+// The edit of every element in either set: the elements only in `right` are inserted, then the
+// elements only in `left` are removed and the elements in both are copied.
+pub(crate) fn set_edits<'a, K: Diffable<'a> + 'a>(
+    left: impl IntoIterator<Item = &'a K> + Clone,
+    right: impl IntoIterator<Item = &'a K>,
+    left_contains: impl Fn(&K) -> bool,
+    right_contains: impl Fn(&K) -> bool + Copy,
+) -> impl Iterator<Item = (&'a K, set::Edit<'a, K>)> {
+    let unique_other = right
+        .into_iter()
+        .filter(move |k| !left_contains(k))
+        .map(|k| (k, set::Edit::Insert(k)));
+    let unique_self = left
+        .clone()
+        .into_iter()
+        .filter(move |k| !right_contains(k))
+        .map(|k| (k, set::Edit::Remove(k)));
+    let intersection = left
+        .into_iter()
+        .filter(move |k| right_contains(k))
+        .map(|k| (k, set::Edit::Copy(k)));
 
-                    let unique_self = self.iter().filter(|k| !other.contains(*k));
+    unique_other.chain(unique_self).chain(intersection)
+}
 
-                    let unique_other = other.iter().filter(|k| !self.contains(*k));
+macro_rules! set_impl {
+    (@new $typ:ident, $set:expr) => {
+        $typ::new()
+    };
+    (@new $typ:ident, $set:expr, $hasher:ident) => {
+        $typ::with_hasher($set.hasher().clone())
+    };
+    ($(($typ:ident, $key_constraint:ident, $diff_type:ident $(, $hasher:ident)?)),*) => {
+        $(
+            impl<'a, K: Diffable<'a> + Eq + $key_constraint + 'a $(, $hasher: BuildHasher + Clone + 'a)?> Diffable<'a> for $typ<K $(, $hasher)?> {
+                type Diff = $diff_type<&'a K, set::Edit<'a, K> $(, $hasher)?>;
 
-                    let value_diffs = unique_other
-                        .map(|k| (k, set::Edit::Insert(k)))
-                        .chain(unique_self.map(|k| (k, set::Edit::Remove(k))))
-                        .chain(intersection.map(|k| (k, set::Edit::Copy(k))))
-                        .collect::<$diff_type<_, _>>();
+                fn diff(&'a self, other: &'a Self) -> Edit<'a, Self> {
+                    let mut value_diffs = set_impl!(@new $diff_type, self $(, $hasher)?);
+                    value_diffs.extend(set_edits(
+                        self,
+                        other,
+                        |k| self.contains(k),
+                        |k| other.contains(k),
+                    ));
 
                     if value_diffs.iter().any(|(_, edit)| !edit.is_copy()) {
                         Edit::Change(value_diffs)
@@ -37,17 +62,45 @@ macro_rules! set_impl {
 
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
-    hash::Hash,
+    hash::{BuildHasher, Hash},
 };
 set_impl! {
     (BTreeSet, Ord, BTreeMap),
-    (HashSet, Hash, HashMap)
+    (HashSet, Hash, HashMap, S)
 }
 
 #[cfg(feature = "indexmap-impl")]
 use indexmap::{IndexMap, IndexSet};
 #[cfg(feature = "indexmap-impl")]
-set_impl! { (IndexSet, Hash, IndexMap) }
+set_impl! { (IndexSet, Hash, IndexMap, S) }
+
+#[cfg(feature = "hashbrown-impl")]
+use hashbrown::{HashMap as HashbrownMap, HashSet as HashbrownSet};
+#[cfg(feature = "hashbrown-impl")]
+set_impl! { (HashbrownSet, Hash, HashbrownMap, S) }
+
+/// Diffs two slices as sets, ignoring the order and duplicates of their elements.
+pub fn diff_as_set<'a, T: Diffable<'a> + Ord + 'a>(
+    left: &'a [T],
+    right: &'a [T],
+) -> as_set::Edit<'a, T> {
+    let left_set = left.iter().collect::<BTreeSet<_>>();
+    let right_set = right.iter().collect::<BTreeSet<_>>();
+
+    let value_diffs = set_edits(
+        left_set.iter().copied(),
+        right_set.iter().copied(),
+        |k| left_set.contains(k),
+        |k| right_set.contains(k),
+    )
+    .collect::<as_set::Diff<'a, T>>();
+
+    if value_diffs.values().any(|edit| !edit.is_copy()) {
+        as_set::Edit::Change(value_diffs)
+    } else {
+        as_set::Edit::Copy(left)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -55,8 +108,8 @@ mod tests {
 
     #[test]
     fn example() {
-        let unity: std::collections::HashSet<_, _> = [1, 2, 3].iter().cloned().collect();
-        let not_unity: std::collections::HashSet<_, _> = [1, 2, 4].iter().cloned().collect();
+        let unity: std::collections::HashSet<_> = [1, 2, 3].iter().cloned().collect();
+        let not_unity: std::collections::HashSet<_> = [1, 2, 4].iter().cloned().collect();
 
         if let Edit::Change(diff) = unity.diff(&not_unity) {
             assert!(diff[&1].is_copy());
@@ -67,4 +120,65 @@ mod tests {
             unreachable!()
         }
     }
+
+    #[cfg(feature = "indexmap-impl")]
+    #[test]
+    fn indexset_order() {
+        let left: IndexSet<_> = [1, 2, 3, 4].iter().cloned().collect();
+        let right: IndexSet<_> = [1, 3, 5].iter().cloned().collect();
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(
+            diff.keys().map(|k| **k).collect::<Vec<_>>(),
+            vec![5, 2, 4, 1, 3]
+        );
+    }
+
+    #[test]
+    fn custom_hasher() {
+        use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+
+        type Set = HashSet<u32, BuildHasherDefault<DefaultHasher>>;
+
+        let left: Set = [1, 2].iter().cloned().collect();
+        let right: Set = [1, 3].iter().cloned().collect();
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff[&1].is_copy());
+        assert!(diff[&2].is_remove());
+        assert!(diff[&3].is_insert());
+    }
+
+    #[cfg(feature = "hashbrown-impl")]
+    #[test]
+    fn hashbrown() {
+        let left: HashbrownSet<_> = [1, 2].iter().cloned().collect();
+        let right: HashbrownSet<_> = [1, 3].iter().cloned().collect();
+
+        let diff = left.diff(&right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff[&1].is_copy());
+        assert!(diff[&2].is_remove());
+        assert!(diff[&3].is_insert());
+    }
+
+    #[test]
+    fn as_set() {
+        let left = vec!["a", "b", "b"];
+        let right = vec!["c", "b", "a"];
+
+        let diff = diff_as_set(&left, &right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(diff.len(), 3);
+        assert!(diff[&"a"].is_copy());
+        assert!(diff[&"c"].is_insert());
+
+        assert!(diff_as_set(&left, &["b", "a"]).is_copy());
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
 impl<'a> Diffable<'a> for str {
     type Diff = Vec<string::Edit>;
 
-    fn diff(&'a self, other: &'a Self) -> edit::Edit<Self> {
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
         let s = lcs::lcs(
             || self.chars(),
             || other.chars(),
@@ -27,7 +27,7 @@ impl<'a> Diffable<'a> for str {
 impl<'a> Diffable<'a> for String {
     type Diff = <str as Diffable<'a>>::Diff;
 
-    fn diff(&'a self, other: &'a Self) -> edit::Edit<Self> {
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
         match self.as_str().diff(other.as_str()) {
             edit::Edit::Change(diff) => edit::Edit::Change(diff),
             edit::Edit::Copy(_) => edit::Edit::Copy(self),
@@ -75,7 +75,7 @@ mod tests {
         let left = "XMJYAUZ";
         let right = "MZJAWXU";
 
-        let diff = left.diff(&right);
+        let diff = left.diff(right);
         if let edit::Edit::Change(diff) = diff {
             assert_eq!(
                 diff.into_iter().collect::<Vec<_>>(),
@@ -0,0 +1,66 @@
+use crate::{
+    edit::{self, time::Delta},
+    Diffable,
+};
+use std::time::{Duration, SystemTime};
+
+impl<'a> Diffable<'a> for Duration {
+    type Diff = Delta;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        if self == other {
+            edit::Edit::Copy(self)
+        } else {
+            edit::Edit::Change(Delta::between(*self, *other))
+        }
+    }
+}
+
+impl<'a> Diffable<'a> for SystemTime {
+    type Diff = Delta;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        if self == other {
+            edit::Edit::Copy(self)
+        } else {
+            edit::Edit::Change(match other.duration_since(*self) {
+                Ok(duration) => Delta::Increase(duration),
+                Err(error) => Delta::Decrease(error.duration()),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration() {
+        let left = Duration::from_secs(3);
+
+        assert!(left.diff(&Duration::from_secs(3)).is_copy());
+        assert_eq!(
+            left.diff(&Duration::from_secs(5)).change(),
+            Some(&Delta::Increase(Duration::from_secs(2)))
+        );
+        assert_eq!(
+            left.diff(&Duration::from_secs(1))
+                .change()
+                .unwrap()
+                .as_secs_f64(),
+            -2.0
+        );
+    }
+
+    #[test]
+    fn system_time() {
+        let left = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+
+        assert!(left.diff(&left.clone()).is_copy());
+        assert_eq!(
+            left.diff(&SystemTime::UNIX_EPOCH).change(),
+            Some(&Delta::Decrease(Duration::from_secs(10)))
+        );
+    }
+}
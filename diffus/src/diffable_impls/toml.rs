@@ -0,0 +1,135 @@
+use crate::{
+    diffable_impls::map::map_edits,
+    edit::{self, collection, enm, toml as toml_edit},
+    lcs, Diffable, Same,
+};
+use toml::Value;
+
+impl<'a> Diffable<'a> for Value {
+    type Diff = enm::Edit<'a, Self, toml_edit::Diff<'a>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let diff = match (self, other) {
+            (Value::String(_), Value::String(_))
+            | (Value::Integer(_), Value::Integer(_))
+            | (Value::Integer(_), Value::Float(_))
+            | (Value::Float(_), Value::Integer(_))
+            | (Value::Float(_), Value::Float(_))
+            | (Value::Boolean(_), Value::Boolean(_))
+            | (Value::Datetime(_), Value::Datetime(_)) => {
+                if self.same(other) {
+                    None
+                } else {
+                    Some(toml_edit::Diff::Scalar(self, other))
+                }
+            }
+            (Value::Array(left), Value::Array(right)) => {
+                let edits = lcs::lcs_post_change(lcs::lcs(
+                    || left.iter(),
+                    || right.iter(),
+                    left.len(),
+                    right.len(),
+                ))
+                .collect::<Vec<_>>();
+
+                if edits.iter().all(collection::Edit::is_copy) {
+                    None
+                } else {
+                    Some(toml_edit::Diff::Array(edits))
+                }
+            }
+            (Value::Table(left), Value::Table(right)) => {
+                let edits = map_edits(
+                    left,
+                    right,
+                    |key| left.contains_key(key),
+                    |key| right.get(key),
+                )
+                .collect::<std::collections::BTreeMap<_, _>>();
+
+                if edits.values().all(|edit| edit.is_copy()) {
+                    None
+                } else {
+                    Some(toml_edit::Diff::Table(edits))
+                }
+            }
+            _ => return edit::Edit::Change(enm::Edit::VariantChanged(self, other)),
+        };
+
+        match diff {
+            Some(diff) => edit::Edit::Change(enm::Edit::AssociatedChanged(diff)),
+            None => edit::Edit::Copy(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert!(Value::Integer(1).diff(&Value::Float(1.0)).is_copy());
+        assert!(Value::Integer(9007199254740993)
+            .diff(&Value::Float(9007199254740992.0))
+            .is_change());
+        assert!(Value::Float(f64::NAN).same(&Value::Float(f64::NAN)));
+    }
+
+    #[test]
+    fn table() {
+        let left: Value = toml::from_str(
+            r#"
+            name = "diffus"
+            version = 1
+            released = 2020-01-01T00:00:00Z
+            authors = ["a", "b"]
+
+            [dependencies]
+            serde = "1"
+            "#,
+        )
+        .unwrap();
+        let right: Value = toml::from_str(
+            r#"
+            name = "diffus"
+            version = 1.0
+            released = 2021-01-01T00:00:00Z
+            authors = ["a", "c"]
+
+            [dependencies]
+            serde = { version = "1" }
+            "#,
+        )
+        .unwrap();
+
+        let diff = left.diff(&right);
+        let edits = match diff.change() {
+            Some(enm::Edit::AssociatedChanged(toml_edit::Diff::Table(edits))) => edits,
+            _ => unreachable!(),
+        };
+
+        assert!(edits[&"name".to_owned()].is_copy());
+        assert!(edits[&"version".to_owned()].is_copy());
+        assert_eq!(
+            edits[&"released".to_owned()].change(),
+            Some(&enm::Edit::AssociatedChanged(toml_edit::Diff::Scalar(
+                &left["released"],
+                &right["released"]
+            )))
+        );
+        assert!(edits[&"authors".to_owned()].is_change());
+        match edits[&"dependencies".to_owned()].change() {
+            Some(enm::Edit::AssociatedChanged(toml_edit::Diff::Table(dependencies))) => assert_eq!(
+                dependencies[&"serde".to_owned()].change(),
+                Some(&enm::Edit::VariantChanged(
+                    &left["dependencies"]["serde"],
+                    &right["dependencies"]["serde"]
+                ))
+            ),
+            _ => unreachable!(),
+        }
+
+        assert!(left.diff(&left.clone()).is_copy());
+    }
+}
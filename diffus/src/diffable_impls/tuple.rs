@@ -0,0 +1,78 @@
+use crate::{edit, Diffable};
+
+macro_rules! tuple_impl {
+    ($(($($name:ident $index:tt),+)),*) => {
+        $(
+            impl<'a, $($name: Diffable<'a> + 'a),+> Diffable<'a> for ($($name,)+) {
+                type Diff = ($(edit::Edit<'a, $name>,)+);
+
+                fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+                    let diff = ($(self.$index.diff(&other.$index),)+);
+
+                    if $(diff.$index.is_copy())&&+ {
+                        edit::Edit::Copy(self)
+                    } else {
+                        edit::Edit::Change(diff)
+                    }
+                }
+            }
+        )*
+    }
+}
+
+tuple_impl! {
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy() {
+        assert!((1, "a".to_owned()).diff(&(1, "a".to_owned())).is_copy());
+    }
+
+    #[test]
+    fn change() {
+        let left = (1, 2, 3);
+        let right = (1, 5, 3);
+
+        assert_eq!(
+            left.diff(&right).change(),
+            Some(&(
+                edit::Edit::Copy(&1),
+                edit::Edit::Change((&2, &5)),
+                edit::Edit::Copy(&3)
+            ))
+        );
+    }
+
+    #[test]
+    fn collection_of_tuples() {
+        use edit::collection::Edit::*;
+
+        let left = vec![("a".to_owned(), 1), ("b".to_owned(), 2)];
+        let right = vec![("a".to_owned(), 1), ("c".to_owned(), 2)];
+
+        assert_eq!(
+            left.diff(&right).change(),
+            Some(&vec![
+                Copy(&("a".to_owned(), 1)),
+                Remove(&("b".to_owned(), 2)),
+                Insert(&("c".to_owned(), 2)),
+            ])
+        );
+    }
+}
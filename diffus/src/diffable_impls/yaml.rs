@@ -0,0 +1,132 @@
+use crate::{
+    diffable_impls::map::left_ordered_map_edits,
+    edit::{self, collection, enm, yaml},
+    lcs, Diffable, Same,
+};
+use serde_yaml::Value;
+
+impl<'a> Diffable<'a> for Value {
+    type Diff = enm::Edit<'a, Self, yaml::Diff<'a>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let diff = match (self, other) {
+            (Value::Null, Value::Null) => None,
+            (Value::Bool(_), Value::Bool(_))
+            | (Value::Number(_), Value::Number(_))
+            | (Value::String(_), Value::String(_)) => {
+                if self.same(other) {
+                    None
+                } else {
+                    Some(yaml::Diff::Scalar(self, other))
+                }
+            }
+            (Value::Sequence(left), Value::Sequence(right)) => {
+                let edits = lcs::lcs_post_change(lcs::lcs(
+                    || left.iter(),
+                    || right.iter(),
+                    left.len(),
+                    right.len(),
+                ))
+                .collect::<Vec<_>>();
+
+                if edits.iter().all(collection::Edit::is_copy) {
+                    None
+                } else {
+                    Some(yaml::Diff::Sequence(edits))
+                }
+            }
+            (Value::Mapping(left), Value::Mapping(right)) => {
+                let edits = left_ordered_map_edits(
+                    left,
+                    right,
+                    |key| left.contains_key(key),
+                    |key| right.get(key),
+                )
+                .collect::<Vec<_>>();
+
+                if edits.iter().all(|(_, edit)| edit.is_copy()) {
+                    None
+                } else {
+                    Some(yaml::Diff::Mapping(edits))
+                }
+            }
+            (Value::Tagged(left), Value::Tagged(right)) if left.tag == right.tag => {
+                match left.value.diff(&right.value) {
+                    edit::Edit::Copy(_) => None,
+                    edit::Edit::Change(diff) => Some(yaml::Diff::Tagged(Box::new(diff))),
+                }
+            }
+            _ => return edit::Edit::Change(enm::Edit::VariantChanged(self, other)),
+        };
+
+        match diff {
+            Some(diff) => edit::Edit::Change(enm::Edit::AssociatedChanged(diff)),
+            None => edit::Edit::Copy(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert!(Value::from(1).diff(&Value::from(1.0)).is_copy());
+        assert!(Value::from(9007199254740993u64)
+            .diff(&Value::from(9007199254740992.0))
+            .is_change());
+        assert!(Value::from(f64::NAN).same(&Value::from(f64::NAN)));
+    }
+
+    #[test]
+    fn mapping() {
+        let left: Value = serde_yaml::from_str(
+            r#"
+            replicas: 1
+            image: !Registry app:1
+            ports: [80, 443]
+            1: numeric key
+            "#,
+        )
+        .unwrap();
+        let right: Value = serde_yaml::from_str(
+            r#"
+            replicas: 1.0
+            image: !Registry app:2
+            ports: [80, 8443]
+            1: numeric key
+            env: prod
+            "#,
+        )
+        .unwrap();
+
+        let diff = left.diff(&right);
+        let edits = match diff.change() {
+            Some(enm::Edit::AssociatedChanged(yaml::Diff::Mapping(edits))) => edits,
+            _ => unreachable!(),
+        };
+        let edit = |key: Value| &edits.iter().find(|(k, _)| **k == key).unwrap().1;
+
+        assert_eq!(edits.len(), 5);
+        assert!(edit("replicas".into()).is_copy());
+        assert!(edit(1.into()).is_copy());
+        assert!(edit("ports".into()).is_change());
+        assert!(edit("env".into()).is_insert());
+        assert_eq!(edits.last().unwrap().0, &Value::from("env"));
+        match edit("image".into()).change() {
+            Some(enm::Edit::AssociatedChanged(yaml::Diff::Tagged(diff))) => assert!(matches!(
+                **diff,
+                enm::Edit::AssociatedChanged(yaml::Diff::Scalar(_, _))
+            )),
+            _ => unreachable!(),
+        }
+
+        let retagged: Value = serde_yaml::from_str("!Other app:1").unwrap();
+        assert!(matches!(
+            left["image"].diff(&retagged).change(),
+            Some(enm::Edit::VariantChanged(_, _))
+        ));
+        assert!(left.diff(&left.clone()).is_copy());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{edit::map, Diffable};
+use std::collections::BTreeMap;
+
+pub type Diff<'a, K, V> = BTreeMap<&'a K, map::Edit<'a, V>>;
+
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serialize-impl",
+    serde(bound = "K: serde::Serialize, V: serde::Serialize, V::Diff: serde::Serialize")
+)]
+pub enum Edit<'a, K, V: Diffable<'a>> {
+    Copy(&'a [(K, V)]),
+    Change(Diff<'a, K, V>),
+}
+
+impl<'a, K, V: Diffable<'a>> Edit<'a, K, V> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn copy(&self) -> Option<&'a [(K, V)]> {
+        if let Self::Copy(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_))
+    }
+
+    pub fn change(&self) -> Option<&Diff<'a, K, V>> {
+        if let Self::Change(value_diff) = self {
+            Some(value_diff)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, K: std::fmt::Debug, V: Diffable<'a> + std::fmt::Debug> std::fmt::Debug for Edit<'a, K, V>
+where
+    V::Diff: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Copy(value) => f.debug_tuple("Copy").field(value).finish(),
+            Self::Change(diff) => f.debug_tuple("Change").field(diff).finish(),
+        }
+    }
+}
+
+impl<'a, K: PartialEq, V: Diffable<'a> + PartialEq> PartialEq for Edit<'a, K, V>
+where
+    V::Diff: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Copy(left), Self::Copy(right)) => left == right,
+            (Self::Change(left), Self::Change(right)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl<'a, K, V: Diffable<'a>> Clone for Edit<'a, K, V>
+where
+    V::Diff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
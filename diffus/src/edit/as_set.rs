@@ -0,0 +1,46 @@
+use crate::{edit::set, Diffable};
+use std::collections::BTreeMap;
+
+pub type Diff<'a, T> = BTreeMap<&'a T, set::Edit<'a, T>>;
+
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum Edit<'a, T: Diffable<'a>> {
+    Copy(&'a [T]),
+    Change(Diff<'a, T>),
+}
+
+impl<'a, T: Diffable<'a>> Edit<'a, T> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn copy(&self) -> Option<&'a [T]> {
+        if let Self::Copy(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_))
+    }
+
+    pub fn change(&self) -> Option<&Diff<'a, T>> {
+        if let Self::Change(value_diff) = self {
+            Some(value_diff)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: Diffable<'a>> Clone for Edit<'a, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
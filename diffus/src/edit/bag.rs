@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+// How many times an element occurs on the left and on the right of a multiset diff.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Copy(usize),
+    Increase { from: usize, to: usize },
+    Decrease { from: usize, to: usize },
+}
+
+impl Count {
+    pub fn new(from: usize, to: usize) -> Self {
+        use std::cmp::Ordering::*;
+
+        match from.cmp(&to) {
+            Equal => Self::Copy(from),
+            Less => Self::Increase { from, to },
+            Greater => Self::Decrease { from, to },
+        }
+    }
+
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn is_increase(&self) -> bool {
+        matches!(self, Self::Increase { .. })
+    }
+
+    pub fn is_decrease(&self) -> bool {
+        matches!(self, Self::Decrease { .. })
+    }
+
+    pub fn from(&self) -> usize {
+        match self {
+            Self::Copy(count) => *count,
+            Self::Increase { from, .. } | Self::Decrease { from, .. } => *from,
+        }
+    }
+
+    pub fn to(&self) -> usize {
+        match self {
+            Self::Copy(count) => *count,
+            Self::Increase { to, .. } | Self::Decrease { to, .. } => *to,
+        }
+    }
+}
+
+pub type Diff<'a, T> = BTreeMap<&'a T, Count>;
+
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Edit<'a, T> {
+    Copy(&'a [T]),
+    Change(Diff<'a, T>),
+}
+
+impl<'a, T> Edit<'a, T> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn copy(&self) -> Option<&'a [T]> {
+        if let Self::Copy(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_))
+    }
+
+    pub fn change(&self) -> Option<&Diff<'a, T>> {
+        if let Self::Change(value_diff) = self {
+            Some(value_diff)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> Clone for Edit<'a, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
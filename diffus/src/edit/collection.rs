@@ -1,5 +1,3 @@
-use crate::Same;
-
 #[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
 #[derive(Debug, PartialEq, Eq)]
 pub enum Edit<'a, T: ?Sized, Diff> {
@@ -9,29 +7,17 @@ pub enum Edit<'a, T: ?Sized, Diff> {
     Change(Diff),
 }
 
-impl<'a, T: Same + ?Sized, Diff> Edit<'a, T, Diff> {
+impl<'a, T: ?Sized, Diff> Edit<'a, T, Diff> {
     pub fn is_copy(&self) -> bool {
-        if let Self::Copy(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Copy(_))
     }
 
     pub fn is_insert(&self) -> bool {
-        if let Self::Insert(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Insert(_))
     }
 
     pub fn is_remove(&self) -> bool {
-        if let Self::Remove(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Remove(_))
     }
 
     pub fn is_change(&self) -> bool {
@@ -70,3 +56,14 @@ impl<'a, T: Same + ?Sized, Diff> Edit<'a, T, Diff> {
         }
     }
 }
+
+impl<'a, T: ?Sized, Diff: Clone> Clone for Edit<'a, T, Diff> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Insert(value) => Self::Insert(value),
+            Self::Remove(value) => Self::Remove(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
@@ -8,27 +8,15 @@ pub enum Edit<'a, T: ?Sized, Diff> {
 
 impl<'a, T: ?Sized, Diff> Edit<'a, T, Diff> {
     pub fn is_copy(&self) -> bool {
-        if let Self::Copy(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Copy(_))
     }
 
     pub fn is_variant_changed(&self) -> bool {
-        if let Self::VariantChanged(_, _) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::VariantChanged(_, _))
     }
 
     pub fn is_associated_changed(&self) -> bool {
-        if let Self::AssociatedChanged(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::AssociatedChanged(_))
     }
 
     pub fn variant_changed(&self) -> Option<(&'a T, &'a T)> {
@@ -47,3 +35,78 @@ impl<'a, T: ?Sized, Diff> Edit<'a, T, Diff> {
         }
     }
 }
+
+impl<'a, T: ?Sized, Diff: Clone> Clone for Edit<'a, T, Diff> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::VariantChanged(left, right) => Self::VariantChanged(left, right),
+            Self::AssociatedChanged(diff) => Self::AssociatedChanged(diff.clone()),
+        }
+    }
+}
+
+// Like `Edit`, but a variant change also carries a diff of what the two variants have in common.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, Eq, PartialEq)]
+pub enum DetailedEdit<'a, T: ?Sized, Diff, VariantDiff> {
+    Copy(&'a T),
+    VariantChanged(&'a T, &'a T, VariantDiff),
+    AssociatedChanged(Diff),
+}
+
+impl<'a, T: ?Sized, Diff, VariantDiff> DetailedEdit<'a, T, Diff, VariantDiff> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn is_variant_changed(&self) -> bool {
+        matches!(self, Self::VariantChanged(_, _, _))
+    }
+
+    pub fn is_associated_changed(&self) -> bool {
+        matches!(self, Self::AssociatedChanged(_))
+    }
+
+    pub fn variant_changed(&self) -> Option<(&'a T, &'a T, &VariantDiff)> {
+        if let Self::VariantChanged(left, right, variant_diff) = self {
+            Some((left, right, variant_diff))
+        } else {
+            None
+        }
+    }
+
+    pub fn associated_change(&self) -> Option<&Diff> {
+        if let Self::AssociatedChanged(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: ?Sized, Diff: Clone, VariantDiff: Clone> Clone
+    for DetailedEdit<'a, T, Diff, VariantDiff>
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::VariantChanged(left, right, variant_diff) => {
+                Self::VariantChanged(left, right, variant_diff.clone())
+            }
+            Self::AssociatedChanged(diff) => Self::AssociatedChanged(diff.clone()),
+        }
+    }
+}
+
+impl<'a, T: ?Sized, Diff, VariantDiff> From<DetailedEdit<'a, T, Diff, VariantDiff>>
+    for Edit<'a, T, Diff>
+{
+    fn from(edit: DetailedEdit<'a, T, Diff, VariantDiff>) -> Self {
+        match edit {
+            DetailedEdit::Copy(value) => Self::Copy(value),
+            DetailedEdit::VariantChanged(left, right, _) => Self::VariantChanged(left, right),
+            DetailedEdit::AssociatedChanged(diff) => Self::AssociatedChanged(diff),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::edit::{self, collection, enm, map};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+// The change of a JSON value that kept its type. Changes of type are a `VariantChanged` of the
+// enclosing `enm::Edit`.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum Diff<'a> {
+    Scalar(&'a Value, &'a Value),
+    Array(Vec<collection::Edit<'a, Value, enm::Edit<'a, Value, Diff<'a>>>>),
+    Object(BTreeMap<&'a String, map::Edit<'a, Value>>),
+}
+
+impl<'a> Clone for Diff<'a> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Scalar(left, right) => Self::Scalar(left, right),
+            Self::Array(edits) => Self::Array(edits.clone()),
+            Self::Object(edits) => Self::Object(edits.clone()),
+        }
+    }
+}
+
+impl<'a> edit::Edit<'a, Value> {
+    /// The diff as a JSON Patch (RFC 6902) document that turns the left value into the right one.
+    pub fn json_patch(&self) -> Value {
+        let mut operations = Vec::new();
+        if let Self::Change(diff) = self {
+            patch(diff, "", &mut operations);
+        }
+        Value::Array(operations)
+    }
+
+    /// The JSON Pointer (RFC 6901) of every value added, removed or replaced by `json_patch`.
+    pub fn paths(&self) -> Vec<String> {
+        let mut operations = Vec::new();
+        if let Self::Change(diff) = self {
+            patch(diff, "", &mut operations);
+        }
+        operations
+            .into_iter()
+            .filter_map(|mut operation| match operation["path"].take() {
+                Value::String(path) => Some(path),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn operation(op: &str, path: &str, value: Option<&Value>) -> Value {
+    let mut operation = serde_json::Map::new();
+    operation.insert("op".to_owned(), op.into());
+    operation.insert("path".to_owned(), path.into());
+    if let Some(value) = value {
+        operation.insert("value".to_owned(), value.clone());
+    }
+    Value::Object(operation)
+}
+
+// Array indices refer to the array as patched by the preceding operations.
+fn patch(diff: &enm::Edit<'_, Value, Diff<'_>>, path: &str, operations: &mut Vec<Value>) {
+    match diff {
+        enm::Edit::Copy(_) => {}
+        enm::Edit::VariantChanged(_, right)
+        | enm::Edit::AssociatedChanged(Diff::Scalar(_, right)) => {
+            operations.push(operation("replace", path, Some(right)))
+        }
+        enm::Edit::AssociatedChanged(Diff::Array(edits)) => {
+            let mut index = 0;
+            for edit in edits {
+                let path = format!("{}/{}", path, index);
+                match edit {
+                    collection::Edit::Copy(_) => index += 1,
+                    collection::Edit::Insert(value) => {
+                        operations.push(operation("add", &path, Some(value)));
+                        index += 1;
+                    }
+                    collection::Edit::Remove(_) => {
+                        operations.push(operation("remove", &path, None));
+                    }
+                    collection::Edit::Change(diff) => {
+                        patch(diff, &path, operations);
+                        index += 1;
+                    }
+                }
+            }
+        }
+        enm::Edit::AssociatedChanged(Diff::Object(edits)) => {
+            for (key, edit) in edits {
+                let path = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                match edit {
+                    map::Edit::Copy(_) => {}
+                    map::Edit::Insert(value) => {
+                        operations.push(operation("add", &path, Some(value)))
+                    }
+                    map::Edit::Remove(_) => operations.push(operation("remove", &path, None)),
+                    map::Edit::Change(diff) => patch(diff, &path, operations),
+                }
+            }
+        }
+    }
+}
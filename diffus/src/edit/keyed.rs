@@ -0,0 +1,93 @@
+use crate::{edit::collection, Diffable};
+
+pub type Diff<'a, C> = Vec<
+    collection::Edit<
+        'a,
+        <C as IntoIterator>::Item,
+        <<C as IntoIterator>::Item as Diffable<'a>>::Diff,
+    >,
+>;
+
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serialize-impl",
+    serde(
+        bound = "C: serde::Serialize, C::Item: serde::Serialize, <C::Item as Diffable<'a>>::Diff: serde::Serialize"
+    )
+)]
+pub enum Edit<'a, C: IntoIterator + 'a>
+where
+    C::Item: Diffable<'a>,
+{
+    Copy(&'a C),
+    Change(Diff<'a, C>),
+}
+
+impl<'a, C: IntoIterator + 'a> Edit<'a, C>
+where
+    C::Item: Diffable<'a>,
+{
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn copy(&self) -> Option<&'a C> {
+        if let Self::Copy(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_))
+    }
+
+    pub fn change(&self) -> Option<&Diff<'a, C>> {
+        if let Self::Change(value_diff) = self {
+            Some(value_diff)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, C: IntoIterator + std::fmt::Debug + 'a> std::fmt::Debug for Edit<'a, C>
+where
+    C::Item: Diffable<'a> + std::fmt::Debug,
+    <C::Item as Diffable<'a>>::Diff: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Copy(value) => f.debug_tuple("Copy").field(value).finish(),
+            Self::Change(diff) => f.debug_tuple("Change").field(diff).finish(),
+        }
+    }
+}
+
+impl<'a, C: IntoIterator + PartialEq + 'a> PartialEq for Edit<'a, C>
+where
+    C::Item: Diffable<'a> + PartialEq,
+    <C::Item as Diffable<'a>>::Diff: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Copy(left), Self::Copy(right)) => left == right,
+            (Self::Change(left), Self::Change(right)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl<'a, C: IntoIterator + 'a> Clone for Edit<'a, C>
+where
+    C::Item: Diffable<'a>,
+    <C::Item as Diffable<'a>>::Diff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
@@ -20,32 +20,16 @@ impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
     // assert_eq!(Edit::Remove.is_insert(), false);
     // ```
     pub fn is_insert(&self) -> bool {
-        if let Self::Insert(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Insert(_))
     }
     pub fn is_remove(&self) -> bool {
-        if let Self::Remove(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Remove(_))
     }
     pub fn is_copy(&self) -> bool {
-        if let Self::Copy(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Copy(_))
     }
     pub fn is_change(&self) -> bool {
-        if let Self::Change(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Change(_))
     }
     pub fn insert(&self) -> Option<&'a T> {
         if let Self::Insert(value) = self {
@@ -76,3 +60,17 @@ impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
         }
     }
 }
+
+impl<'a, T: Diffable<'a> + ?Sized> Clone for Edit<'a, T>
+where
+    T::Diff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Insert(value) => Self::Insert(value),
+            Self::Remove(value) => Self::Remove(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
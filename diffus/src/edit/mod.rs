@@ -1,8 +1,25 @@
+pub mod as_map;
+pub mod as_set;
+pub mod bag;
 pub mod collection;
 pub mod enm;
+#[cfg(feature = "serde_json-impl")]
+pub mod json;
+pub mod keyed;
 pub mod map;
+pub mod option;
+pub mod ordered;
+pub mod renamed;
+#[cfg(feature = "serde-value")]
+pub mod serialized;
 pub mod set;
+pub mod sorted;
 pub mod string;
+pub mod time;
+#[cfg(feature = "toml-impl")]
+pub mod toml;
+#[cfg(feature = "serde_yaml-impl")]
+pub mod yaml;
 
 use crate::Diffable;
 
@@ -15,11 +32,7 @@ pub enum Edit<'a, T: Diffable<'a> + ?Sized> {
 
 impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
     pub fn is_copy(&self) -> bool {
-        if let Self::Copy(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Copy(_))
     }
 
     pub fn copy(&self) -> Option<&'a T> {
@@ -31,11 +44,7 @@ impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
     }
 
     pub fn is_change(&self) -> bool {
-        if let Self::Change(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Change(_))
     }
 
     pub fn change(&self) -> Option<&T::Diff> {
@@ -47,11 +56,23 @@ impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
     }
 }
 
-impl<'a, Diff, T: Diffable<'a, Diff = Diff> + 'a> Into<map::Edit<'a, T>> for Edit<'a, T> {
-    fn into(self) -> map::Edit<'a, T> {
+impl<'a, Diff, T: Diffable<'a, Diff = Diff> + 'a> From<Edit<'a, T>> for map::Edit<'a, T> {
+    fn from(edit: Edit<'a, T>) -> Self {
+        match edit {
+            Edit::Copy(value) => Self::Copy(value),
+            Edit::Change(diff) => Self::Change(diff),
+        }
+    }
+}
+
+impl<'a, T: Diffable<'a> + ?Sized> Clone for Edit<'a, T>
+where
+    T::Diff: Clone,
+{
+    fn clone(&self) -> Self {
         match self {
-            Self::Copy(value) => map::Edit::Copy(value),
-            Self::Change(diff) => map::Edit::Change(diff),
+            Self::Copy(value) => Self::Copy(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
         }
     }
 }
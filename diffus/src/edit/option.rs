@@ -0,0 +1,74 @@
+use crate::Diffable;
+
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Edit<'a, T: Diffable<'a>> {
+    Copy(&'a Option<T>),
+    Set(&'a T),
+    Unset(&'a T),
+    Changed(T::Diff),
+}
+
+impl<'a, T: Diffable<'a>> Edit<'a, T> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+
+    pub fn is_unset(&self) -> bool {
+        matches!(self, Self::Unset(_))
+    }
+
+    pub fn is_changed(&self) -> bool {
+        matches!(self, Self::Changed(_))
+    }
+
+    pub fn copy(&self) -> Option<&'a Option<T>> {
+        if let Self::Copy(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&self) -> Option<&'a T> {
+        if let Self::Set(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn unset(&self) -> Option<&'a T> {
+        if let Self::Unset(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn changed(&self) -> Option<&T::Diff> {
+        if let Self::Changed(value_diff) = self {
+            Some(value_diff)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: Diffable<'a>> Clone for Edit<'a, T>
+where
+    T::Diff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Set(value) => Self::Set(value),
+            Self::Unset(value) => Self::Unset(value),
+            Self::Changed(diff) => Self::Changed(diff.clone()),
+        }
+    }
+}
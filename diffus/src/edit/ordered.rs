@@ -0,0 +1,168 @@
+use crate::{edit, Diffable, OrderedDiffable};
+
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Edit<'a, T: OrderedDiffable<'a> + ?Sized> {
+    Copy(&'a T),
+    Change(T::OrderedDiff),
+}
+
+impl<'a, T: OrderedDiffable<'a> + ?Sized> Edit<'a, T> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn copy(&self) -> Option<&'a T> {
+        if let Self::Copy(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_))
+    }
+
+    pub fn change(&self) -> Option<&T::OrderedDiff> {
+        if let Self::Change(value_diff) = self {
+            Some(value_diff)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: OrderedDiffable<'a> + ?Sized> Clone for Edit<'a, T>
+where
+    T::OrderedDiff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
+
+// An entry of an ordered map diff. Keys present in both maps but out of order are reported once,
+// as a `Move` from their index on the left to their index on the right.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum MapEntry<'a, K, V: Diffable<'a>> {
+    Copy(&'a K, &'a V),
+    Insert(&'a K, &'a V),
+    Remove(&'a K, &'a V),
+    Change(&'a K, V::Diff),
+    Move {
+        key: &'a K,
+        from: usize,
+        to: usize,
+        value: edit::Edit<'a, V>,
+    },
+}
+
+impl<'a, K, V: Diffable<'a>> MapEntry<'a, K, V> {
+    pub fn key(&self) -> &'a K {
+        match self {
+            Self::Copy(key, _) | Self::Insert(key, _) | Self::Remove(key, _) => key,
+            Self::Change(key, _) => key,
+            Self::Move { key, .. } => key,
+        }
+    }
+
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_, _))
+    }
+
+    pub fn is_insert(&self) -> bool {
+        matches!(self, Self::Insert(_, _))
+    }
+
+    pub fn is_remove(&self) -> bool {
+        matches!(self, Self::Remove(_, _))
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_, _))
+    }
+
+    pub fn is_move(&self) -> bool {
+        matches!(self, Self::Move { .. })
+    }
+}
+
+impl<'a, K, V: Diffable<'a>> Clone for MapEntry<'a, K, V>
+where
+    V::Diff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(key, value) => Self::Copy(key, value),
+            Self::Insert(key, value) => Self::Insert(key, value),
+            Self::Remove(key, value) => Self::Remove(key, value),
+            Self::Change(key, diff) => Self::Change(key, diff.clone()),
+            Self::Move {
+                key,
+                from,
+                to,
+                value,
+            } => Self::Move {
+                key,
+                from: *from,
+                to: *to,
+                value: value.clone(),
+            },
+        }
+    }
+}
+
+// An entry of an ordered set diff, see `MapEntry`.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum SetEntry<'a, K> {
+    Copy(&'a K),
+    Insert(&'a K),
+    Remove(&'a K),
+    Move { key: &'a K, from: usize, to: usize },
+}
+
+impl<'a, K> SetEntry<'a, K> {
+    pub fn key(&self) -> &'a K {
+        match self {
+            Self::Copy(key) | Self::Insert(key) | Self::Remove(key) => key,
+            Self::Move { key, .. } => key,
+        }
+    }
+
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn is_insert(&self) -> bool {
+        matches!(self, Self::Insert(_))
+    }
+
+    pub fn is_remove(&self) -> bool {
+        matches!(self, Self::Remove(_))
+    }
+
+    pub fn is_move(&self) -> bool {
+        matches!(self, Self::Move { .. })
+    }
+}
+
+impl<'a, K> Clone for SetEntry<'a, K> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(key) => Self::Copy(key),
+            Self::Insert(key) => Self::Insert(key),
+            Self::Remove(key) => Self::Remove(key),
+            Self::Move { key, from, to } => Self::Move {
+                key,
+                from: *from,
+                to: *to,
+            },
+        }
+    }
+}
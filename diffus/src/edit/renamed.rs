@@ -0,0 +1,125 @@
+use crate::{
+    edit::{self, map},
+    Diffable, RenameDiffable,
+};
+
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Edit<'a, T: RenameDiffable<'a> + ?Sized> {
+    Copy(&'a T),
+    Change(T::RenameDiff),
+}
+
+impl<'a, T: RenameDiffable<'a> + ?Sized> Edit<'a, T> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn copy(&self) -> Option<&'a T> {
+        if let Self::Copy(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_))
+    }
+
+    pub fn change(&self) -> Option<&T::RenameDiff> {
+        if let Self::Change(value_diff) = self {
+            Some(value_diff)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: RenameDiffable<'a> + ?Sized> Clone for Edit<'a, T>
+where
+    T::RenameDiff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+        }
+    }
+}
+
+// A map entry of a rename-aware diff. A `Renamed` entry is stored under its new key and replaces
+// the `Remove` of its old key.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum MapEntry<'a, K, V: Diffable<'a>> {
+    Copy(&'a V),
+    Insert(&'a V),
+    Remove(&'a V),
+    Change(V::Diff),
+    Renamed {
+        from: &'a K,
+        to: &'a K,
+        diff: edit::Edit<'a, V>,
+    },
+}
+
+impl<'a, K, V: Diffable<'a>> MapEntry<'a, K, V> {
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy(_))
+    }
+
+    pub fn is_insert(&self) -> bool {
+        matches!(self, Self::Insert(_))
+    }
+
+    pub fn is_remove(&self) -> bool {
+        matches!(self, Self::Remove(_))
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, Self::Change(_))
+    }
+
+    pub fn is_renamed(&self) -> bool {
+        matches!(self, Self::Renamed { .. })
+    }
+
+    pub fn renamed(&self) -> Option<(&'a K, &'a K, &edit::Edit<'a, V>)> {
+        if let Self::Renamed { from, to, diff } = self {
+            Some((from, to, diff))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, K, V: Diffable<'a>> From<map::Edit<'a, V>> for MapEntry<'a, K, V> {
+    fn from(edit: map::Edit<'a, V>) -> Self {
+        match edit {
+            map::Edit::Copy(value) => Self::Copy(value),
+            map::Edit::Insert(value) => Self::Insert(value),
+            map::Edit::Remove(value) => Self::Remove(value),
+            map::Edit::Change(diff) => Self::Change(diff),
+        }
+    }
+}
+
+impl<'a, K, V: Diffable<'a>> Clone for MapEntry<'a, K, V>
+where
+    V::Diff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Insert(value) => Self::Insert(value),
+            Self::Remove(value) => Self::Remove(value),
+            Self::Change(diff) => Self::Change(diff.clone()),
+            Self::Renamed { from, to, diff } => Self::Renamed {
+                from,
+                to,
+                diff: diff.clone(),
+            },
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    edit::{collection, enm, map},
+    serialized::Value,
+};
+
+// The change of a serialized value that kept its shape. Changes of type, of struct name or of enum
+// variant are a `VariantChanged` of the enclosing `enm::Edit`. Map entries and struct fields are
+// listed in the order of the left value, followed by the ones only in the right value.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum Diff<'a> {
+    Scalar(&'a Value, &'a Value),
+    Some(Box<enm::Edit<'a, Value, Diff<'a>>>),
+    Seq(Vec<collection::Edit<'a, Value, enm::Edit<'a, Value, Diff<'a>>>>),
+    Map(Vec<(&'a Value, map::Edit<'a, Value>)>),
+    Struct(Vec<(&'static str, map::Edit<'a, Value>)>),
+    Variant(Box<enm::Edit<'a, Value, Diff<'a>>>),
+}
+
+impl<'a> Clone for Diff<'a> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Scalar(left, right) => Self::Scalar(left, right),
+            Self::Some(diff) => Self::Some(diff.clone()),
+            Self::Seq(edits) => Self::Seq(edits.clone()),
+            Self::Map(edits) => Self::Map(edits.clone()),
+            Self::Struct(edits) => Self::Struct(edits.clone()),
+            Self::Variant(diff) => Self::Variant(diff.clone()),
+        }
+    }
+}
@@ -10,11 +10,7 @@ pub enum Edit<'a, T: Diffable<'a> + ?Sized> {
 
 impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
     pub fn is_copy(&self) -> bool {
-        if let Self::Copy(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Copy(_))
     }
     //
     // Checks if the edit is an insert.
@@ -26,18 +22,10 @@ impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
     // assert_eq!(Edit::Remove.is_insert(), false);
     // ```
     pub fn is_insert(&self) -> bool {
-        if let Self::Insert(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Insert(_))
     }
     pub fn is_remove(&self) -> bool {
-        if let Self::Remove(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Remove(_))
     }
     pub fn copy(&self) -> Option<&'a T> {
         if let Self::Copy(value) = self {
@@ -61,3 +49,13 @@ impl<'a, T: Diffable<'a> + ?Sized> Edit<'a, T> {
         }
     }
 }
+
+impl<'a, T: Diffable<'a> + ?Sized> Clone for Edit<'a, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Copy(value) => Self::Copy(value),
+            Self::Insert(value) => Self::Insert(value),
+            Self::Remove(value) => Self::Remove(value),
+        }
+    }
+}
@@ -21,26 +21,14 @@ impl From<lcs::Edit<char>> for Edit {
 
 impl Edit {
     pub fn is_copy(&self) -> bool {
-        if let Self::Copy(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Copy(_))
     }
     pub fn is_insert(&self) -> bool {
-        if let Self::Insert(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Insert(_))
     }
 
     pub fn is_remove(&self) -> bool {
-        if let Self::Remove(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Self::Remove(_))
     }
 
     pub fn copy(self) -> Option<char> {
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+// The signed difference between two durations or points in time.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Delta {
+    Increase(Duration),
+    Decrease(Duration),
+}
+
+impl Delta {
+    pub fn between(left: Duration, right: Duration) -> Self {
+        if right >= left {
+            Self::Increase(right - left)
+        } else {
+            Self::Decrease(left - right)
+        }
+    }
+
+    pub fn is_increase(&self) -> bool {
+        matches!(self, Self::Increase(_))
+    }
+
+    pub fn is_decrease(&self) -> bool {
+        matches!(self, Self::Decrease(_))
+    }
+
+    pub fn magnitude(&self) -> Duration {
+        match self {
+            Self::Increase(duration) | Self::Decrease(duration) => *duration,
+        }
+    }
+
+    pub fn as_secs_f64(&self) -> f64 {
+        match self {
+            Self::Increase(duration) => duration.as_secs_f64(),
+            Self::Decrease(duration) => -duration.as_secs_f64(),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::edit::{collection, enm, map};
+use std::collections::BTreeMap;
+use toml::Value;
+
+// The change of a TOML value that kept its type, with integers and floats both counting as numbers.
+// Changes of type are a `VariantChanged` of the enclosing `enm::Edit`.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum Diff<'a> {
+    Scalar(&'a Value, &'a Value),
+    Array(Vec<collection::Edit<'a, Value, enm::Edit<'a, Value, Diff<'a>>>>),
+    Table(BTreeMap<&'a String, map::Edit<'a, Value>>),
+}
+
+impl<'a> Clone for Diff<'a> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Scalar(left, right) => Self::Scalar(left, right),
+            Self::Array(edits) => Self::Array(edits.clone()),
+            Self::Table(edits) => Self::Table(edits.clone()),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::edit::{collection, enm, map};
+use serde_yaml::Value;
+
+// The change of a YAML value that kept its type, or of the value of a tagged value that kept its
+// tag. Changes of type or tag are a `VariantChanged` of the enclosing `enm::Edit`. Mapping keys are
+// values themselves, so mapping changes are listed in the order of the left mapping, followed by
+// the keys only in the right one.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum Diff<'a> {
+    Scalar(&'a Value, &'a Value),
+    Sequence(Vec<collection::Edit<'a, Value, enm::Edit<'a, Value, Diff<'a>>>>),
+    Mapping(Vec<(&'a Value, map::Edit<'a, Value>)>),
+    Tagged(Box<enm::Edit<'a, Value, Diff<'a>>>),
+}
+
+impl<'a> Clone for Diff<'a> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Scalar(left, right) => Self::Scalar(left, right),
+            Self::Sequence(edits) => Self::Sequence(edits.clone()),
+            Self::Mapping(edits) => Self::Mapping(edits.clone()),
+            Self::Tagged(diff) => Self::Tagged(diff.clone()),
+        }
+    }
+}
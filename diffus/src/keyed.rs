@@ -0,0 +1,236 @@
+use crate::{
+    edit::{self, collection, keyed},
+    lcs, Diffable, Same,
+};
+
+struct Keyed<'a, T, K> {
+    value: &'a T,
+    key: K,
+}
+
+impl<'a, T, K: Same> Same for Keyed<'a, T, K> {
+    fn same(&self, other: &Self) -> bool {
+        self.key.same(&other.key)
+    }
+}
+
+/// Diffs two collections using LCS, matching elements by the key returned from `key` instead of
+/// requiring the elements to implement `Same`.
+pub fn diff_by_key<'a, C, T, K, F>(left: &'a C, right: &'a C, key: F) -> keyed::Edit<'a, C>
+where
+    C: IntoIterator<Item = T> + 'a,
+    &'a C: IntoIterator<Item = &'a T>,
+    T: Diffable<'a> + 'a,
+    K: Same,
+    F: Fn(&'a T) -> K,
+{
+    let keyed = |collection: &'a C| {
+        collection
+            .into_iter()
+            .map(|value| Keyed {
+                key: key(value),
+                value,
+            })
+            .collect::<Vec<_>>()
+    };
+    let left_keyed = keyed(left);
+    let right_keyed = keyed(right);
+
+    let s = lcs::lcs(
+        || left_keyed.iter(),
+        || right_keyed.iter(),
+        left_keyed.len(),
+        right_keyed.len(),
+    )
+    .map(|edit| match edit {
+        lcs::Edit::Same(left, right) => match left.value.diff(right.value) {
+            edit::Edit::Copy(value) => collection::Edit::Copy(value),
+            edit::Edit::Change(diff) => collection::Edit::Change(diff),
+        },
+        lcs::Edit::Insert(value) => collection::Edit::Insert(value.value),
+        lcs::Edit::Remove(value) => collection::Edit::Remove(value.value),
+    })
+    .collect::<Vec<_>>();
+
+    if s.iter().all(collection::Edit::is_copy) {
+        keyed::Edit::Copy(left)
+    } else {
+        keyed::Edit::Change(s)
+    }
+}
+
+/// Diffs two sets, pairing each element with an element of the other set that has the `Same` key
+/// returned from `key`. Paired elements that differ are reported as a `Change` instead of a
+/// `Remove` and an `Insert`. Elements are compared pairwise, so this is quadratic in the set size.
+pub fn diff_set_by_key<'a, C, T, K, F>(left: &'a C, right: &'a C, key: F) -> keyed::Edit<'a, C>
+where
+    C: IntoIterator<Item = T> + 'a,
+    &'a C: IntoIterator<Item = &'a T>,
+    T: Diffable<'a> + 'a,
+    K: Same,
+    F: Fn(&'a T) -> K,
+{
+    let keyed = |collection: &'a C| {
+        collection
+            .into_iter()
+            .map(|value| Keyed {
+                key: key(value),
+                value,
+            })
+            .collect::<Vec<_>>()
+    };
+    let left_keyed = keyed(left);
+    let mut right_keyed = keyed(right).into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut s = left_keyed
+        .iter()
+        .map(|left| {
+            let right = right_keyed
+                .iter_mut()
+                .find(|right| match right {
+                    Some(right) => left.same(right),
+                    None => false,
+                })
+                .and_then(Option::take);
+
+            match right {
+                Some(right) => match left.value.diff(right.value) {
+                    edit::Edit::Copy(value) => collection::Edit::Copy(value),
+                    edit::Edit::Change(diff) => collection::Edit::Change(diff),
+                },
+                None => collection::Edit::Remove(left.value),
+            }
+        })
+        .collect::<Vec<_>>();
+    s.extend(
+        right_keyed
+            .into_iter()
+            .flatten()
+            .map(|right| collection::Edit::Insert(right.value)),
+    );
+
+    if s.iter().all(collection::Edit::is_copy) {
+        keyed::Edit::Copy(left)
+    } else {
+        keyed::Edit::Change(s)
+    }
+}
+
+/// Diffs two sets like `diff_set_by_key`, pairing elements that are the `Same`.
+pub fn diff_set_by_same<'a, C, T>(left: &'a C, right: &'a C) -> keyed::Edit<'a, C>
+where
+    C: IntoIterator<Item = T> + 'a,
+    &'a C: IntoIterator<Item = &'a T>,
+    T: Diffable<'a> + Same + 'a,
+{
+    diff_set_by_key(left, right, |value| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Entry {
+        id: u32,
+        value: u32,
+    }
+
+    impl<'a> Diffable<'a> for Entry {
+        type Diff = (&'a u32, &'a u32);
+
+        fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+            if self.value == other.value {
+                edit::Edit::Copy(self)
+            } else {
+                edit::Edit::Change((&self.value, &other.value))
+            }
+        }
+    }
+
+    #[test]
+    fn copy() {
+        let left = vec![Entry { id: 1, value: 0 }, Entry { id: 2, value: 0 }];
+        let right = vec![Entry { id: 1, value: 0 }, Entry { id: 2, value: 0 }];
+
+        assert!(diff_by_key(&left, &right, |entry| entry.id).is_copy());
+    }
+
+    #[test]
+    fn change_by_key() {
+        let left = vec![
+            Entry { id: 1, value: 0 },
+            Entry { id: 2, value: 0 },
+            Entry { id: 3, value: 0 },
+        ];
+        let right = vec![
+            Entry { id: 1, value: 0 },
+            Entry { id: 2, value: 1 },
+            Entry { id: 4, value: 0 },
+        ];
+
+        use collection::Edit::*;
+
+        assert_eq!(
+            diff_by_key(&left, &right, |entry| entry.id).change(),
+            Some(&vec![
+                Copy(&Entry { id: 1, value: 0 }),
+                Change((&0, &1)),
+                Remove(&Entry { id: 3, value: 0 }),
+                Insert(&Entry { id: 4, value: 0 }),
+            ])
+        );
+    }
+
+    #[test]
+    fn set_by_key() {
+        let left: std::collections::BTreeSet<_> =
+            vec![(1, 0), (2, 0), (3, 0)].into_iter().collect();
+        let right: std::collections::BTreeSet<_> =
+            vec![(1, 0), (2, 1), (4, 0)].into_iter().collect();
+
+        let diff = diff_set_by_key(&left, &right, |entry| entry.0);
+        let diff = diff.change().unwrap();
+
+        assert!(diff[0].is_copy());
+        assert_eq!(
+            diff[1].change(),
+            Some(&(edit::Edit::Copy(&2), edit::Edit::Change((&0, &1))))
+        );
+        assert_eq!(diff[2], collection::Edit::Remove(&(3, 0)));
+        assert_eq!(diff[3], collection::Edit::Insert(&(4, 0)));
+
+        assert!(diff_set_by_key(&left, &left.clone(), |entry| entry.0).is_copy());
+    }
+
+    #[test]
+    fn hash_set() {
+        let left: std::collections::HashSet<_> = vec![(1, 0), (2, 0), (3, 0)].into_iter().collect();
+        let right: std::collections::HashSet<_> =
+            vec![(1, 0), (2, 1), (4, 0)].into_iter().collect();
+
+        let diff = diff_set_by_key(&left, &right, |entry| entry.0);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(diff.len(), 4);
+        assert!(diff.contains(&collection::Edit::Copy(&(1, 0))));
+        assert!(diff.contains(&collection::Edit::Change((
+            edit::Edit::Copy(&2),
+            edit::Edit::Change((&0, &1))
+        ))));
+        assert!(diff.contains(&collection::Edit::Remove(&(3, 0))));
+        assert_eq!(diff.last(), Some(&collection::Edit::Insert(&(4, 0))));
+
+        let diff = diff_set_by_same(&left, &right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(diff.len(), 5);
+        assert!(diff.contains(&collection::Edit::Copy(&(1, 0))));
+        assert!(diff.contains(&collection::Edit::Remove(&(2, 0))));
+        assert!(diff.contains(&collection::Edit::Remove(&(3, 0))));
+        assert!(diff[3..].contains(&collection::Edit::Insert(&(2, 1))));
+        assert!(diff[3..].contains(&collection::Edit::Insert(&(4, 0))));
+
+        assert!(diff_set_by_same(&left, &left.clone()).is_copy());
+    }
+}
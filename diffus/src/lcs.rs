@@ -10,11 +10,7 @@ pub enum Edit<T> {
 
 impl<T> Edit<T> {
     pub fn is_same(&self) -> bool {
-        if let Edit::Same(_, _) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Edit::Same(_, _))
     }
 }
 
@@ -88,20 +84,24 @@ fn lcs_base<T: Same>(
                 .unwrap()
                 .same(current_y.as_ref().unwrap())
         {
-            i = i - 1;
-            j = j - 1;
+            i -= 1;
+            j -= 1;
 
             match (current_x, current_y) {
                 (Some(current_x), Some(current_y)) => Some(Edit::Same(current_x, current_y)),
                 _ => unreachable!(),
             }
         } else if current_y.is_some() && (current_x.is_none() || left >= above) {
-            current_x.map(|c| x.put_back(c));
-            j = j - 1;
+            if let Some(c) = current_x {
+                x.put_back(c);
+            }
+            j -= 1;
             current_y.map(|value| Edit::Insert(value))
         } else if current_x.is_some() && (current_y.is_none() || left < above) {
-            current_y.map(|c| y.put_back(c));
-            i = i - 1;
+            if let Some(c) = current_y {
+                y.put_back(c);
+            }
+            i -= 1;
             current_x.map(|value| Edit::Remove(value))
         } else {
             None
@@ -112,12 +112,7 @@ fn lcs_base<T: Same>(
     .rev()
 }
 
-pub(crate) fn lcs<
-    'a,
-    T: Same,
-    I: DoubleEndedIterator<Item = T>,
-    J: DoubleEndedIterator<Item = T>,
->(
+pub(crate) fn lcs<T: Same, I: DoubleEndedIterator<Item = T>, J: DoubleEndedIterator<Item = T>>(
     x: impl Fn() -> I,
     y: impl Fn() -> J,
     x_len: usize,
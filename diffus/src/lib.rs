@@ -1,9 +1,25 @@
+mod bag;
 pub mod diffable_impls;
 pub mod edit;
+mod keyed;
 mod lcs;
+mod ordered;
+mod renamed;
 pub mod same;
+#[cfg(feature = "serde-value")]
+pub mod serialized;
+mod sorted;
 mod twodvec;
 
+pub use bag::{diff_unordered, Bag};
+pub use diffable_impls::{map::diff_as_map, option::diff_option, set::diff_as_set};
+pub use keyed::{diff_by_key, diff_set_by_key, diff_set_by_same};
+pub use ordered::{diff_ordered, OrderedDiffable};
+pub use renamed::{diff_renames, RenameDiffable};
+#[cfg(feature = "serde-value")]
+pub use serialized::diff_serialized;
+pub use sorted::{diff_sorted, SortedDiffable};
+
 pub trait Diffable<'a> {
     type Diff: 'a;
 
@@ -0,0 +1,165 @@
+use crate::edit::ordered;
+
+/// Collections with a meaningful order whose diff also reports entries that moved.
+pub trait OrderedDiffable<'a> {
+    type OrderedDiff: 'a;
+
+    fn diff_ordered(&'a self, other: &'a Self) -> ordered::Edit<'a, Self>;
+}
+
+/// Diffs two ordered maps or sets, matching their keys through LCS and reporting the keys that
+/// are out of order as moves.
+pub fn diff_ordered<'a, T: OrderedDiffable<'a> + ?Sized>(
+    left: &'a T,
+    right: &'a T,
+) -> ordered::Edit<'a, T> {
+    left.diff_ordered(right)
+}
+
+#[cfg(feature = "indexmap-impl")]
+mod indexmap_impl {
+    use super::*;
+    use crate::{edit, lcs, Diffable, Same};
+    use indexmap::{IndexMap, IndexSet};
+    use std::hash::{BuildHasher, Hash};
+
+    struct Key<'a, K>(&'a K);
+
+    impl<'a, K: Eq> Same for Key<'a, K> {
+        fn same(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    fn key_lcs<'a, K: Eq + 'a>(
+        left: impl Iterator<Item = &'a K>,
+        right: impl Iterator<Item = &'a K>,
+    ) -> Vec<lcs::Edit<&'a K>> {
+        let left = left.map(Key).collect::<Vec<_>>();
+        let right = right.map(Key).collect::<Vec<_>>();
+
+        lcs::lcs(|| left.iter(), || right.iter(), left.len(), right.len())
+            .map(|edit| match edit {
+                lcs::Edit::Same(left, right) => lcs::Edit::Same(left.0, right.0),
+                lcs::Edit::Insert(key) => lcs::Edit::Insert(key.0),
+                lcs::Edit::Remove(key) => lcs::Edit::Remove(key.0),
+            })
+            .collect()
+    }
+
+    impl<'a, K: Eq + Hash + 'a, V: Diffable<'a> + 'a, S: BuildHasher + 'a> OrderedDiffable<'a>
+        for IndexMap<K, V, S>
+    {
+        type OrderedDiff = Vec<ordered::MapEntry<'a, K, V>>;
+
+        fn diff_ordered(&'a self, other: &'a Self) -> ordered::Edit<'a, Self> {
+            let entries = key_lcs(self.keys(), other.keys())
+                .into_iter()
+                .filter_map(|edit| match edit {
+                    lcs::Edit::Same(key, _) => Some(match self[key].diff(&other[key]) {
+                        edit::Edit::Copy(value) => ordered::MapEntry::Copy(key, value),
+                        edit::Edit::Change(diff) => ordered::MapEntry::Change(key, diff),
+                    }),
+                    lcs::Edit::Remove(key) if other.contains_key(key) => None,
+                    lcs::Edit::Remove(key) => Some(ordered::MapEntry::Remove(key, &self[key])),
+                    lcs::Edit::Insert(key) => Some(match self.get_full(key) {
+                        Some((from, _, value)) => ordered::MapEntry::Move {
+                            key,
+                            from,
+                            to: other.get_index_of(key).expect("key of the right map"),
+                            value: value.diff(&other[key]),
+                        },
+                        None => ordered::MapEntry::Insert(key, &other[key]),
+                    }),
+                })
+                .collect::<Vec<_>>();
+
+            if entries.iter().all(ordered::MapEntry::is_copy) {
+                ordered::Edit::Copy(self)
+            } else {
+                ordered::Edit::Change(entries)
+            }
+        }
+    }
+
+    impl<'a, K: Eq + Hash + 'a, S: BuildHasher + 'a> OrderedDiffable<'a> for IndexSet<K, S> {
+        type OrderedDiff = Vec<ordered::SetEntry<'a, K>>;
+
+        fn diff_ordered(&'a self, other: &'a Self) -> ordered::Edit<'a, Self> {
+            let entries = key_lcs(self.iter(), other.iter())
+                .into_iter()
+                .filter_map(|edit| match edit {
+                    lcs::Edit::Same(key, _) => Some(ordered::SetEntry::Copy(key)),
+                    lcs::Edit::Remove(key) if other.contains(key) => None,
+                    lcs::Edit::Remove(key) => Some(ordered::SetEntry::Remove(key)),
+                    lcs::Edit::Insert(key) => Some(match self.get_index_of(key) {
+                        Some(from) => ordered::SetEntry::Move {
+                            key,
+                            from,
+                            to: other.get_index_of(key).expect("key of the right set"),
+                        },
+                        None => ordered::SetEntry::Insert(key),
+                    }),
+                })
+                .collect::<Vec<_>>();
+
+            if entries.iter().all(ordered::SetEntry::is_copy) {
+                ordered::Edit::Copy(self)
+            } else {
+                ordered::Edit::Change(entries)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn map() {
+            let left: IndexMap<_, _> = vec![(1, 1), (2, 2), (3, 3), (4, 4)].into_iter().collect();
+            let right: IndexMap<_, _> = vec![(1, 1), (3, 3), (2, 5), (5, 5)].into_iter().collect();
+
+            assert!(diff_ordered(&left, &left.clone()).is_copy());
+
+            use ordered::MapEntry::*;
+
+            assert_eq!(
+                diff_ordered(&left, &right).change(),
+                Some(&vec![
+                    Copy(&1, &1),
+                    Copy(&3, &3),
+                    Remove(&4, &4),
+                    Move {
+                        key: &2,
+                        from: 1,
+                        to: 2,
+                        value: edit::Edit::Change((&2, &5)),
+                    },
+                    Insert(&5, &5),
+                ])
+            );
+        }
+
+        #[test]
+        fn set() {
+            let left: IndexSet<_> = vec![1, 2, 3].into_iter().collect();
+            let right: IndexSet<_> = vec![3, 1, 2].into_iter().collect();
+
+            use ordered::SetEntry::*;
+
+            assert_eq!(
+                diff_ordered(&left, &right).change(),
+                Some(&vec![
+                    Move {
+                        key: &3,
+                        from: 2,
+                        to: 0
+                    },
+                    Copy(&1),
+                    Copy(&2),
+                ])
+            );
+        }
+    }
+}
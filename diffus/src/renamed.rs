@@ -0,0 +1,163 @@
+use crate::{
+    edit::{self, map, renamed},
+    Diffable, Same,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+/// Maps whose diff can pair removed keys with inserted keys holding the `Same` value, reporting
+/// them as renames.
+pub trait RenameDiffable<'a>: Diffable<'a> {
+    type RenameDiff: 'a;
+
+    fn detect_renames(diff: Self::Diff) -> Self::RenameDiff;
+}
+
+/// Diffs two maps like `Diffable::diff`, with a removed and an inserted key whose values are
+/// `Same` reported as a single `Renamed` entry under the new key.
+pub fn diff_renames<'a, T: RenameDiffable<'a> + ?Sized>(
+    left: &'a T,
+    right: &'a T,
+) -> renamed::Edit<'a, T> {
+    match left.diff(right) {
+        edit::Edit::Copy(value) => renamed::Edit::Copy(value),
+        edit::Edit::Change(diff) => renamed::Edit::Change(T::detect_renames(diff)),
+    }
+}
+
+// Pairs every inserted value with the first unpaired removed value that is the `Same`, returning
+// `(from, to, from_value, to_value)`.
+fn renames<'a, 'b, K: 'a, V: Diffable<'a> + Same + 'a>(
+    edits: impl Iterator<Item = (&'b &'a K, &'b map::Edit<'a, V>)> + Clone,
+) -> Vec<(&'a K, &'a K, &'a V, &'a V)>
+where
+    'a: 'b,
+{
+    let mut removed = edits
+        .clone()
+        .filter_map(|(key, edit)| Some((*key, edit.remove()?)))
+        .map(Some)
+        .collect::<Vec<_>>();
+
+    edits
+        .filter_map(|(to, edit)| {
+            let inserted = edit.insert()?;
+            let (from, removed) = removed
+                .iter_mut()
+                .find(|candidate| match candidate {
+                    Some((_, value)) => (*value).same(inserted),
+                    None => false,
+                })?
+                .take()?;
+
+            Some((from, *to, removed, inserted))
+        })
+        .collect()
+}
+
+macro_rules! rename_map_impl {
+    (@new $typ:ident, $map:expr) => {
+        $typ::new()
+    };
+    (@new $typ:ident, $map:expr, $hasher:ident) => {
+        $typ::with_hasher($map.hasher().clone())
+    };
+    ($(($typ:ident, $key_constraint:ident $(, $hasher:ident)?)),*) => {
+        $(
+            impl<'a, K: Eq + $key_constraint + 'a, V: Diffable<'a> + Same + 'a $(, $hasher: BuildHasher + Clone + 'a)?> RenameDiffable<'a> for $typ<K, V $(, $hasher)?> {
+                type RenameDiff = $typ<&'a K, renamed::MapEntry<'a, K, V> $(, $hasher)?>;
+
+                fn detect_renames(diff: Self::Diff) -> Self::RenameDiff {
+                    let renames = renames(diff.iter());
+
+                    let mut entries = rename_map_impl!(@new $typ, diff $(, $hasher)?);
+                    entries.extend(diff.into_iter().map(|(key, edit)| (key, edit.into())));
+
+                    for (from, to, from_value, to_value) in renames {
+                        entries.remove(&from);
+                        entries.insert(
+                            to,
+                            renamed::MapEntry::Renamed {
+                                from,
+                                to,
+                                diff: from_value.diff(to_value),
+                            },
+                        );
+                    }
+
+                    entries
+                }
+            }
+        )*
+    }
+}
+
+rename_map_impl! {
+    (BTreeMap, Ord),
+    (HashMap, Hash, S)
+}
+
+#[cfg(feature = "indexmap-impl")]
+use indexmap::IndexMap;
+#[cfg(feature = "indexmap-impl")]
+rename_map_impl! { (IndexMap, Hash, S) }
+
+#[cfg(feature = "hashbrown-impl")]
+use hashbrown::HashMap as HashbrownMap;
+#[cfg(feature = "hashbrown-impl")]
+rename_map_impl! { (HashbrownMap, Hash, S) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let left: BTreeMap<_, _> = vec![("host", "localhost"), ("port", "80"), ("user", "root")]
+            .into_iter()
+            .collect();
+        let right: BTreeMap<_, _> = vec![
+            ("hostname", "localhost"),
+            ("port", "8080"),
+            ("username", "admin"),
+        ]
+        .into_iter()
+        .collect();
+
+        let diff = diff_renames(&left, &right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(
+            diff.keys().copied().collect::<Vec<_>>(),
+            vec![&"hostname", &"port", &"user", &"username"]
+        );
+        assert_eq!(
+            diff[&"hostname"],
+            renamed::MapEntry::Renamed {
+                from: &"host",
+                to: &"hostname",
+                diff: edit::Edit::Copy(&"localhost"),
+            }
+        );
+        assert!(diff[&"port"].is_change());
+        assert!(diff[&"user"].is_remove());
+        assert!(diff[&"username"].is_insert());
+
+        assert!(diff_renames(&left, &left.clone()).is_copy());
+    }
+
+    #[test]
+    fn duplicate_values() {
+        let left: HashMap<_, _> = vec![(1, 0), (2, 0)].into_iter().collect();
+        let right: HashMap<_, _> = vec![(3, 0), (4, 0), (5, 0)].into_iter().collect();
+
+        let diff = diff_renames(&left, &right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(diff.values().filter(|entry| entry.is_renamed()).count(), 2);
+        assert_eq!(diff.values().filter(|entry| entry.is_insert()).count(), 1);
+        assert_eq!(diff.len(), 3);
+    }
+}
@@ -10,6 +10,16 @@ impl<T: Same> Same for Option<T> {
     }
 }
 
+impl<T: Same, E: Same> Same for Result<T, E> {
+    fn same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Ok(a), Ok(b)) => a.same(b),
+            (Err(a), Err(b)) => a.same(b),
+            _ => false,
+        }
+    }
+}
+
 macro_rules! same_for_eq {
     ($($typ:ty),*) => {
         $(
@@ -24,6 +34,53 @@ macro_rules! same_for_eq {
 
 same_for_eq! { i64, i32, i16, i8, u64, u32, u16, u8, char, str, bool, isize, usize, () }
 
+same_for_eq! { i128, u128 }
+
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+same_for_eq! {
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize
+}
+
+use std::{
+    cmp::Ordering,
+    ffi::{OsStr, OsString},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+same_for_eq! {
+    Ordering, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, OsStr, OsString,
+    Path, PathBuf, Duration, SystemTime
+}
+
+impl<T: Same> Same for std::num::Wrapping<T> {
+    fn same(&self, other: &Self) -> bool {
+        self.0.same(&other.0)
+    }
+}
+
+impl<T: Same> Same for std::ops::Range<T> {
+    fn same(&self, other: &Self) -> bool {
+        self.start.same(&other.start) && self.end.same(&other.end)
+    }
+}
+
+impl<T: ?Sized> Same for std::marker::PhantomData<T> {
+    fn same(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<B: Same + ToOwned + ?Sized> Same for std::borrow::Cow<'_, B> {
+    fn same(&self, other: &Self) -> bool {
+        (**self).same(&**other)
+    }
+}
+
 macro_rules! same_for_float {
     ($($typ:ty),*) => {
         $(
@@ -44,8 +101,318 @@ same_for_eq! { snake_case::SnakeCase }
 #[cfg(feature = "uuid-impl")]
 same_for_eq! { uuid::Uuid }
 
+// A number of a self-describing format, compared numerically so that `1` and `1.0` are the same.
+#[cfg(any(
+    feature = "serde_json-impl",
+    feature = "toml-impl",
+    feature = "serde_yaml-impl"
+))]
+#[derive(Clone, Copy)]
+enum Numeric {
+    Integer(i128),
+    Float(f64),
+}
+
+#[cfg(any(
+    feature = "serde_json-impl",
+    feature = "toml-impl",
+    feature = "serde_yaml-impl"
+))]
+impl Same for Numeric {
+    fn same(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Numeric::Integer(a), Numeric::Integer(b)) => a == b,
+            (Numeric::Float(a), Numeric::Float(b)) => a.same(&b),
+            // Compared as integers, as integers above 2^53 are rounded when converted to floats.
+            (Numeric::Integer(a), Numeric::Float(b)) | (Numeric::Float(b), Numeric::Integer(a)) => {
+                let bound = 2f64.powi(127);
+                b.fract() == 0.0 && b >= -bound && b < bound && b as i128 == a
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "serde_json-impl", feature = "serde_yaml-impl"))]
+macro_rules! numeric_from_serde {
+    ($($typ:ty),*) => {
+        $(
+            impl From<&$typ> for Numeric {
+                fn from(number: &$typ) -> Self {
+                    match (number.as_i64(), number.as_u64()) {
+                        (Some(integer), _) => Numeric::Integer(integer.into()),
+                        (_, Some(integer)) => Numeric::Integer(integer.into()),
+                        _ => Numeric::Float(number.as_f64().unwrap_or(f64::NAN)),
+                    }
+                }
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "serde_json-impl")]
+numeric_from_serde! { serde_json::Number }
+
+#[cfg(feature = "serde_yaml-impl")]
+numeric_from_serde! { serde_yaml::Number }
+
+// Values are the `Same` if they are equal, with numbers compared as `Numeric`s.
+#[cfg(feature = "serde_json-impl")]
+impl Same for serde_json::Value {
+    fn same(&self, other: &Self) -> bool {
+        use serde_json::Value::*;
+
+        match (self, other) {
+            (Number(a), Number(b)) => Numeric::from(a).same(&Numeric::from(b)),
+            (Array(a), Array(b)) => a.same(b),
+            (Object(a), Object(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, a)| matches!(b.get(key), Some(b) if a.same(b)))
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+#[cfg(feature = "toml-impl")]
+impl Same for toml::Value {
+    fn same(&self, other: &Self) -> bool {
+        use toml::Value::*;
+
+        match (self, other) {
+            (Integer(a), Integer(b)) => a == b,
+            (Integer(a), Float(b)) | (Float(b), Integer(a)) => {
+                Numeric::Integer((*a).into()).same(&Numeric::Float(*b))
+            }
+            (Float(a), Float(b)) => a.same(b),
+            (Array(a), Array(b)) => a.same(b),
+            (Table(a), Table(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, a)| matches!(b.get(key), Some(b) if a.same(b)))
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml-impl")]
+impl Same for serde_yaml::Value {
+    fn same(&self, other: &Self) -> bool {
+        use serde_yaml::Value::*;
+
+        match (self, other) {
+            (Number(a), Number(b)) => Numeric::from(a).same(&Numeric::from(b)),
+            (Sequence(a), Sequence(b)) => a.same(b),
+            (Mapping(a), Mapping(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, a)| matches!(b.get(key), Some(b) if a.same(b)))
+            }
+            (Tagged(a), Tagged(b)) => a.tag == b.tag && a.value.same(&b.value),
+            (a, b) => a == b,
+        }
+    }
+}
+
 impl<T: Same + ?Sized> Same for &T {
     fn same(&self, other: &Self) -> bool {
         (*self).same(*other)
     }
 }
+
+impl Same for String {
+    fn same(&self, other: &Self) -> bool {
+        self.as_str().same(other.as_str())
+    }
+}
+
+macro_rules! same_for_borrow {
+    ($($typ:ident),*) => {
+        $(
+            impl<T: Same + ?Sized> Same for $typ<T> {
+                fn same(&self, other: &Self) -> bool {
+                    (**self).same(&**other)
+                }
+            }
+        )*
+    }
+}
+
+use std::{rc::Rc, sync::Arc};
+same_for_borrow! { Box, Rc, Arc }
+
+macro_rules! same_for_collection {
+    ($($typ:ident),*) => {
+        $(
+            impl<T: Same> Same for $typ<T> {
+                fn same(&self, other: &Self) -> bool {
+                    self.len() == other.len()
+                        && self.iter().zip(other.iter()).all(|(a, b)| a.same(b))
+                }
+            }
+        )*
+    }
+}
+
+use std::collections::{BinaryHeap, LinkedList, VecDeque};
+same_for_collection! { LinkedList, Vec, VecDeque }
+
+impl<T: Ord> Same for BinaryHeap<T> {
+    fn same(&self, other: &Self) -> bool {
+        crate::bag::counts(self.iter()) == crate::bag::counts(other.iter())
+    }
+}
+
+impl<T: Same> Same for [T] {
+    fn same(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.same(b))
+    }
+}
+
+impl<T: Same, const N: usize> Same for [T; N] {
+    fn same(&self, other: &Self) -> bool {
+        self[..].same(&other[..])
+    }
+}
+
+macro_rules! same_for_map {
+    ($(($typ:ident, $key_constraint:ident $(, $hasher:ident)?)),*) => {
+        $(
+            impl<K: Eq + $key_constraint, V: Same $(, $hasher: BuildHasher)?> Same for $typ<K, V $(, $hasher)?> {
+                fn same(&self, other: &Self) -> bool {
+                    self.len() == other.len()
+                        && self
+                            .iter()
+                            .all(|(k, v)| other.get(k).map_or(false, |other_v| v.same(other_v)))
+                }
+            }
+        )*
+    }
+}
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+};
+same_for_map! {
+    (BTreeMap, Ord),
+    (HashMap, Hash, S)
+}
+
+#[cfg(feature = "indexmap-impl")]
+use indexmap::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap-impl")]
+same_for_map! { (IndexMap, Hash, S) }
+
+#[cfg(feature = "hashbrown-impl")]
+use hashbrown::{HashMap as HashbrownMap, HashSet as HashbrownSet};
+#[cfg(feature = "hashbrown-impl")]
+same_for_map! { (HashbrownMap, Hash, S) }
+
+macro_rules! same_for_set {
+    ($(($typ:ident, $key_constraint:ident $(, $hasher:ident)?)),*) => {
+        $(
+            impl<K: Eq + $key_constraint $(, $hasher: BuildHasher)?> Same for $typ<K $(, $hasher)?> {
+                fn same(&self, other: &Self) -> bool {
+                    self.len() == other.len() && self.iter().all(|k| other.contains(k))
+                }
+            }
+        )*
+    }
+}
+
+same_for_set! {
+    (BTreeSet, Ord),
+    (HashSet, Hash, S)
+}
+
+#[cfg(feature = "indexmap-impl")]
+same_for_set! { (IndexSet, Hash, S) }
+
+#[cfg(feature = "hashbrown-impl")]
+same_for_set! { (HashbrownSet, Hash, S) }
+
+macro_rules! same_for_tuple {
+    ($(($($name:ident $index:tt),+)),*) => {
+        $(
+            impl<$($name: Same),+> Same for ($($name,)+) {
+                fn same(&self, other: &Self) -> bool {
+                    $(self.$index.same(&other.$index))&&+
+                }
+            }
+        )*
+    }
+}
+
+same_for_tuple! {
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collections() {
+        assert!(vec![1, 2].same(&vec![1, 2]));
+        assert!(!vec![1, 2].same(&vec![1]));
+        assert!(!vec![1, 2].same(&vec![1, 3]));
+        assert!(vec!["a".to_owned()].same(&vec!["a".to_owned()]));
+        assert!([1, 2].same(&[1, 2]));
+        assert!(![1, 2].same(&[1, 3]));
+    }
+
+    #[test]
+    fn maps() {
+        let left: HashMap<_, _> = [(1, "a"), (2, "b")].iter().cloned().collect();
+        let right: HashMap<_, _> = [(1, "a"), (2, "c")].iter().cloned().collect();
+
+        assert!(left.same(&left.clone()));
+        assert!(!left.same(&right));
+    }
+
+    #[test]
+    fn sets() {
+        let left: BTreeSet<_> = [1, 2].iter().cloned().collect();
+        let right: BTreeSet<_> = [1, 3].iter().cloned().collect();
+
+        assert!(left.same(&left.clone()));
+        assert!(!left.same(&right));
+    }
+
+    #[test]
+    fn tuples() {
+        assert!((1, "a".to_owned()).same(&(1, "a".to_owned())));
+        assert!(!(1, "a".to_owned()).same(&(1, "b".to_owned())));
+    }
+
+    #[test]
+    fn results() {
+        let ok: Result<u32, u32> = Ok(1);
+
+        assert!(ok.same(&Ok(1)));
+        assert!(!ok.same(&Ok(2)));
+        assert!(!ok.same(&Err(1)));
+    }
+
+    #[test]
+    fn std_types() {
+        assert!(std::time::Duration::from_secs(1).same(&std::time::Duration::from_secs(1)));
+        assert!(Path::new("a/b").same(Path::new("a/b")));
+        assert!((1..2).same(&(1..2)));
+        assert!(!(1..2).same(&(1..3)));
+        assert!(std::borrow::Cow::Borrowed("a").same(&std::borrow::Cow::Owned("a".to_owned())));
+    }
+}
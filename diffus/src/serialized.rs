@@ -0,0 +1,631 @@
+use crate::{
+    diffable_impls::map::left_ordered_map_edits,
+    edit::{self, collection, enm, serialized},
+    lcs, Diffable, Same,
+};
+use serde::ser::{self, Serialize};
+use std::fmt;
+
+/// A value of the serde data model. Newtype structs are serialized as their content and tuple
+/// structs as a `Seq`.
+#[cfg_attr(feature = "serialize-impl", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Unit,
+    Bool(bool),
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Value>),
+    Seq(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Struct(&'static str, Vec<(&'static str, Value)>),
+    Variant(&'static str, Box<Value>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+/// Serializes `value` into a `Value`.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, Error> {
+    value.serialize(Serializer)
+}
+
+/// The serialized left and right values of `diff_serialized`, which their diff borrows from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Serialized {
+    pub left: Value,
+    pub right: Value,
+}
+
+impl Serialized {
+    pub fn diff(&self) -> edit::Edit<'_, Value> {
+        self.left.diff(&self.right)
+    }
+}
+
+/// Serializes two values of a type that implements `Serialize` but not `Diffable`, to diff them
+/// with `Serialized::diff`.
+pub fn diff_serialized<T: Serialize + ?Sized>(left: &T, right: &T) -> Result<Serialized, Error> {
+    Ok(Serialized {
+        left: to_value(left)?,
+        right: to_value(right)?,
+    })
+}
+
+impl<'a> Diffable<'a> for Value {
+    type Diff = enm::Edit<'a, Self, serialized::Diff<'a>>;
+
+    fn diff(&'a self, other: &'a Self) -> edit::Edit<'a, Self> {
+        let diff = match (self, other) {
+            (Value::Unit, Value::Unit) | (Value::None, Value::None) => None,
+            (Value::Bool(_), Value::Bool(_))
+            | (Value::Signed(_), Value::Signed(_))
+            | (Value::Unsigned(_), Value::Unsigned(_))
+            | (Value::Float(_), Value::Float(_))
+            | (Value::Char(_), Value::Char(_))
+            | (Value::String(_), Value::String(_))
+            | (Value::Bytes(_), Value::Bytes(_)) => {
+                if self.same(other) {
+                    None
+                } else {
+                    Some(serialized::Diff::Scalar(self, other))
+                }
+            }
+            (Value::Some(left), Value::Some(right)) => match left.diff(right) {
+                edit::Edit::Copy(_) => None,
+                edit::Edit::Change(diff) => Some(serialized::Diff::Some(diff)),
+            },
+            (Value::Seq(left), Value::Seq(right)) => {
+                let edits = lcs::lcs_post_change(lcs::lcs(
+                    || left.iter(),
+                    || right.iter(),
+                    left.len(),
+                    right.len(),
+                ))
+                .collect::<Vec<_>>();
+
+                if edits.iter().all(collection::Edit::is_copy) {
+                    None
+                } else {
+                    Some(serialized::Diff::Seq(edits))
+                }
+            }
+            (Value::Map(left), Value::Map(right)) => {
+                let edits = entry_edits(left, right);
+
+                if edits.iter().all(|(_, edit)| edit.is_copy()) {
+                    None
+                } else {
+                    Some(serialized::Diff::Map(edits))
+                }
+            }
+            (Value::Struct(left_name, left), Value::Struct(right_name, right))
+                if left_name == right_name =>
+            {
+                let edits = entry_edits(left, right)
+                    .into_iter()
+                    .map(|(name, edit)| (*name, edit))
+                    .collect::<Vec<_>>();
+
+                if edits.iter().all(|(_, edit)| edit.is_copy()) {
+                    None
+                } else {
+                    Some(serialized::Diff::Struct(edits))
+                }
+            }
+            (Value::Variant(left_name, left), Value::Variant(right_name, right))
+                if left_name == right_name =>
+            {
+                match left.diff(right) {
+                    edit::Edit::Copy(_) => None,
+                    edit::Edit::Change(diff) => Some(serialized::Diff::Variant(diff)),
+                }
+            }
+            _ => return edit::Edit::Change(enm::Edit::VariantChanged(self, other)),
+        };
+
+        match diff {
+            Some(diff) => edit::Edit::Change(enm::Edit::AssociatedChanged(diff)),
+            None => edit::Edit::Copy(self),
+        }
+    }
+}
+
+// Map entries and struct fields are matched by key, which may not be hashable or ordered.
+fn entry_edits<'a, K: PartialEq>(
+    left: &'a [(K, Value)],
+    right: &'a [(K, Value)],
+) -> Vec<(&'a K, edit::map::Edit<'a, Value>)> {
+    let get = |entries: &'a [(K, Value)], key: &K| {
+        entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value)
+    };
+
+    left_ordered_map_edits(
+        left.iter().map(|(key, value)| (key, value)),
+        right.iter().map(|(key, value)| (key, value)),
+        |key| get(left, key).is_some(),
+        |key| get(right, key),
+    )
+    .collect()
+}
+
+// Values are the `Same` if they are equal, with floats compared like `f64`s so that `NaN` is the
+// same as itself.
+// Entries are matched by key like in `entry_edits`, so the order of map entries doesn't matter.
+fn same_entries<K: PartialEq>(left: &[(K, Value)], right: &[(K, Value)]) -> bool {
+    left.len() == right.len()
+        && left.iter().all(|(key, value)| {
+            matches!(
+                right.iter().find(|(other_key, _)| other_key == key),
+                Some((_, other_value)) if value.same(other_value)
+            )
+        })
+}
+
+impl Same for Value {
+    fn same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Float(a), Value::Float(b)) => a.same(b),
+            (Value::Some(a), Value::Some(b)) => a.same(b),
+            (Value::Seq(a), Value::Seq(b)) => a.same(b),
+            (Value::Map(a), Value::Map(b)) => same_entries(a, b),
+            (Value::Struct(a_name, a), Value::Struct(b_name, b)) => {
+                a_name == b_name && same_entries(a, b)
+            }
+            (Value::Variant(a_name, a), Value::Variant(b_name, b)) => a_name == b_name && a.same(b),
+            (a, b) => a == b,
+        }
+    }
+}
+
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeSeq;
+    type SerializeTuple = SerializeSeq;
+    type SerializeTupleStruct = SerializeSeq;
+    type SerializeTupleVariant = SerializeVariant<SerializeSeq>;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeStruct;
+    type SerializeStructVariant = SerializeVariant<SerializeStruct>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, Error> {
+        Ok(Value::Signed(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Ok(Value::Float(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::Float(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::String(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        Ok(Value::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        Ok(Value::Some(Box::new(to_value(value)?)))
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Value, Error> {
+        Ok(Value::Struct(name, Vec::new()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::Variant(variant, Box::new(Value::Unit)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        to_value(value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        Ok(Value::Variant(variant, Box::new(to_value(value)?)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeSeq, Error> {
+        Ok(SerializeSeq(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeSeq, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeSeq>, Error> {
+        Ok(SerializeVariant(variant, self.serialize_seq(Some(len))?))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap(Vec::with_capacity(len.unwrap_or(0)), None))
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<SerializeStruct, Error> {
+        Ok(SerializeStruct(name, Vec::with_capacity(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeStruct>, Error> {
+        Ok(SerializeVariant(
+            variant,
+            self.serialize_struct(variant, len)?,
+        ))
+    }
+}
+
+struct SerializeSeq(Vec<Value>);
+
+impl SerializeSeq {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.0.push(to_value(value)?);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Seq(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Seq(self.0))
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Seq(self.0))
+    }
+}
+
+struct SerializeMap(Vec<(Value, Value)>, Option<Value>);
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.1 = Some(to_value(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .1
+            .take()
+            .ok_or_else(|| Error("map value serialized before its key".to_owned()))?;
+        self.0.push((key, to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Map(self.0))
+    }
+}
+
+struct SerializeStruct(&'static str, Vec<(&'static str, Value)>);
+
+impl ser::SerializeStruct for SerializeStruct {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.1.push((key, to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Struct(self.0, self.1))
+    }
+}
+
+struct SerializeVariant<S>(&'static str, S);
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeSeq> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.1.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Variant(self.0, Box::new(Value::Seq((self.1).0))))
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeStruct> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(&mut self.1, key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Variant(
+            self.0,
+            Box::new(ser::SerializeStruct::end(self.1)?),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[derive(serde::Serialize)]
+    struct Deployment {
+        name: String,
+        replicas: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        image: Option<String>,
+        ports: Vec<u16>,
+        labels: BTreeMap<String, String>,
+        strategy: Strategy,
+    }
+
+    #[derive(serde::Serialize)]
+    enum Strategy {
+        Recreate,
+        Rolling { surge: u32 },
+    }
+
+    fn deployment() -> Deployment {
+        Deployment {
+            name: "web".to_owned(),
+            replicas: 1,
+            image: None,
+            ports: vec![80, 443],
+            labels: vec![("tier".to_owned(), "frontend".to_owned())]
+                .into_iter()
+                .collect(),
+            strategy: Strategy::Rolling { surge: 1 },
+        }
+    }
+
+    #[test]
+    fn copy() {
+        let serialized = diff_serialized(&deployment(), &deployment()).unwrap();
+        assert!(serialized.diff().is_copy());
+    }
+
+    #[test]
+    fn fields() {
+        let left = deployment();
+        let right = Deployment {
+            replicas: 3,
+            image: Some("web:2".to_owned()),
+            ports: vec![80, 8080, 443],
+            labels: vec![("tier".to_owned(), "backend".to_owned())]
+                .into_iter()
+                .collect(),
+            strategy: Strategy::Recreate,
+            ..deployment()
+        };
+
+        let serialized = diff_serialized(&left, &right).unwrap();
+        let diff = serialized.diff();
+        let fields = match diff.change() {
+            Some(enm::Edit::AssociatedChanged(serialized::Diff::Struct(fields))) => fields,
+            _ => unreachable!(),
+        };
+        let field = |name: &str| &fields.iter().find(|(field, _)| *field == name).unwrap().1;
+
+        assert_eq!(
+            fields.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            vec!["name", "replicas", "ports", "labels", "strategy", "image"]
+        );
+        assert!(field("name").is_copy());
+        assert_eq!(
+            field("replicas").change(),
+            Some(&enm::Edit::AssociatedChanged(serialized::Diff::Scalar(
+                &Value::Unsigned(1),
+                &Value::Unsigned(3)
+            )))
+        );
+        assert_eq!(
+            field("image").insert(),
+            Some(&Value::Some(Box::new(Value::String("web:2".to_owned()))))
+        );
+        match field("ports").change() {
+            Some(enm::Edit::AssociatedChanged(serialized::Diff::Seq(edits))) => {
+                assert!(edits[1].is_insert())
+            }
+            _ => unreachable!(),
+        }
+        match field("labels").change() {
+            Some(enm::Edit::AssociatedChanged(serialized::Diff::Map(edits))) => {
+                assert_eq!(edits[0].0, &Value::String("tier".to_owned()));
+                assert!(edits[0].1.is_change());
+            }
+            _ => unreachable!(),
+        }
+        assert!(matches!(
+            field("strategy").change(),
+            Some(enm::Edit::VariantChanged(_, _))
+        ));
+    }
+
+    #[test]
+    fn unordered_maps() {
+        let entries = (0..8)
+            .map(|i| (Value::Unsigned(i), Value::String(i.to_string())))
+            .collect::<Vec<_>>();
+        let left = Value::Seq(vec![Value::Map(entries.clone())]);
+        let right = Value::Seq(vec![Value::Map(entries.into_iter().rev().collect())]);
+
+        assert!(left.same(&right));
+        assert!(left.diff(&right).is_copy());
+
+        let maps = vec![(0..8)
+            .map(|i| (i, i.to_string()))
+            .collect::<std::collections::HashMap<_, _>>()];
+        let other_maps = vec![maps[0].iter().map(|(k, v)| (*k, v.clone())).collect()];
+        let serialized = diff_serialized(&maps, &other_maps).unwrap();
+        assert!(serialized.diff().is_copy());
+    }
+
+    #[test]
+    fn nan() {
+        let serialized = diff_serialized(&f64::NAN, &f64::NAN).unwrap();
+        assert!(serialized.diff().is_copy());
+
+        let serialized = diff_serialized(&vec![1.0, f64::NAN], &vec![2.0, f64::NAN]).unwrap();
+        match serialized.diff().change() {
+            Some(enm::Edit::AssociatedChanged(serialized::Diff::Seq(edits))) => {
+                assert_eq!(edits.iter().filter(|edit| edit.is_copy()).count(), 1);
+                assert!(edits.last().unwrap().is_copy());
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    edit::{self, map, set, sorted},
+    Diffable,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+/// Maps and sets whose diff can be reordered by key, for output that does not depend on the
+/// hasher.
+pub trait SortedDiffable<'a>: Diffable<'a> {
+    type SortedDiff: 'a;
+
+    fn sort_diff(diff: Self::Diff) -> Self::SortedDiff;
+}
+
+/// Diffs two maps or sets like `Diffable::diff`, with the changes sorted by key.
+pub fn diff_sorted<'a, T: SortedDiffable<'a> + ?Sized>(
+    left: &'a T,
+    right: &'a T,
+) -> sorted::Edit<'a, T> {
+    match left.diff(right) {
+        edit::Edit::Copy(value) => sorted::Edit::Copy(value),
+        edit::Edit::Change(diff) => sorted::Edit::Change(T::sort_diff(diff)),
+    }
+}
+
+macro_rules! sorted_map_impl {
+    ($(($typ:ident, $key_constraint:ident $(, $hasher:ident)?)),*) => {
+        $(
+            impl<'a, K: Ord + $key_constraint + 'a, V: Diffable<'a> + 'a $(, $hasher: BuildHasher + Clone + 'a)?> SortedDiffable<'a> for $typ<K, V $(, $hasher)?> {
+                type SortedDiff = BTreeMap<&'a K, map::Edit<'a, V>>;
+
+                fn sort_diff(diff: Self::Diff) -> Self::SortedDiff {
+                    diff.into_iter().collect()
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! sorted_set_impl {
+    ($(($typ:ident, $key_constraint:ident $(, $hasher:ident)?)),*) => {
+        $(
+            impl<'a, K: Diffable<'a> + Ord + $key_constraint + 'a $(, $hasher: BuildHasher + Clone + 'a)?> SortedDiffable<'a> for $typ<K $(, $hasher)?> {
+                type SortedDiff = BTreeMap<&'a K, set::Edit<'a, K>>;
+
+                fn sort_diff(diff: Self::Diff) -> Self::SortedDiff {
+                    diff.into_iter().collect()
+                }
+            }
+        )*
+    }
+}
+
+sorted_map_impl! { (BTreeMap, Ord), (HashMap, Hash, S) }
+sorted_set_impl! { (BTreeSet, Ord), (HashSet, Hash, S) }
+
+#[cfg(feature = "indexmap-impl")]
+use indexmap::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap-impl")]
+sorted_map_impl! { (IndexMap, Hash, S) }
+#[cfg(feature = "indexmap-impl")]
+sorted_set_impl! { (IndexSet, Hash, S) }
+
+#[cfg(feature = "hashbrown-impl")]
+use hashbrown::{HashMap as HashbrownMap, HashSet as HashbrownSet};
+#[cfg(feature = "hashbrown-impl")]
+sorted_map_impl! { (HashbrownMap, Hash, S) }
+#[cfg(feature = "hashbrown-impl")]
+sorted_set_impl! { (HashbrownSet, Hash, S) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let left: HashMap<_, _> = (0..32).map(|i| (i, i)).collect();
+        let right: HashMap<_, _> = (0..32).map(|i| (i, i % 8)).collect();
+
+        let diff = diff_sorted(&left, &right);
+        let keys = diff
+            .change()
+            .unwrap()
+            .keys()
+            .map(|k| **k)
+            .collect::<Vec<_>>();
+
+        assert_eq!(keys, (0..32).collect::<Vec<_>>());
+        assert!(diff_sorted(&left, &left.clone()).is_copy());
+    }
+
+    #[test]
+    fn set() {
+        let left: HashSet<_> = (0..16).collect();
+        let right: HashSet<_> = (8..24).collect();
+
+        let diff = diff_sorted(&left, &right);
+        let diff = diff.change().unwrap();
+
+        assert_eq!(
+            diff.keys().map(|k| **k).collect::<Vec<_>>(),
+            (0..24).collect::<Vec<_>>()
+        );
+        assert!(diff[&0].is_remove());
+        assert!(diff[&8].is_copy());
+        assert!(diff[&23].is_insert());
+    }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Version(u32, u32);
+
+    #[test]
+    fn ord_only_keys() {
+        let left: BTreeMap<_, _> = vec![(Version(1, 0), 1), (Version(1, 1), 1)]
+            .into_iter()
+            .collect();
+        let right: BTreeMap<_, _> = vec![(Version(1, 1), 2), (Version(2, 0), 1)]
+            .into_iter()
+            .collect();
+
+        let diff = diff_sorted(&left, &right);
+        let diff = diff.change().unwrap();
+
+        assert!(diff[&Version(1, 0)].is_remove());
+        assert!(diff[&Version(1, 1)].is_change());
+        assert!(diff[&Version(2, 0)].is_insert());
+    }
+}